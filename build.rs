@@ -0,0 +1,65 @@
+//! Generates the table of solutions for the optional `registry` feature.
+//! Every `src/bin/<year>-<day>.rs` is included as a module of the runner binary, see `src/template/registry.rs`.
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    if env::var_os("CARGO_FEATURE_REGISTRY").is_none() {
+        return;
+    }
+
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+
+    let mut solutions: Vec<(u16, u8, String)> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let (year, day) = path
+                .file_name()?
+                .to_str()?
+                .strip_suffix(".rs")?
+                .split_once('-')?;
+            Some((
+                year.parse().ok()?,
+                day.parse().ok()?,
+                path.to_str()?.to_string(),
+            ))
+        })
+        .collect();
+
+    solutions.sort();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for (year, day, path) in &solutions {
+        let module = format!("solution_{year}_{day:02}");
+        writeln!(
+            modules,
+            "#[cfg(not(test))]\n#[allow(dead_code, unused_imports, clippy::all, clippy::pedantic)]\n#[path = {path:?}]\nmod {module};"
+        )
+        .unwrap();
+        writeln!(
+            entries,
+            "    RegisteredSolution {{ puzzle: PuzzleId::new(advent_of_code::year!({year}), advent_of_code::day!({day})), run: {module}::run_in_process }},"
+        )
+        .unwrap();
+    }
+
+    let registry = format!(
+        "// generated by build.rs, do not edit.\n\
+         use advent_of_code::template::registry::RegisteredSolution;\n\
+         #[allow(unused_imports)]\n\
+         use advent_of_code::PuzzleId;\n\n\
+         {modules}\n\
+         #[cfg(not(test))]\n\
+         pub const SOLUTIONS: &[RegisteredSolution] = &[\n{entries}];\n\n\
+         // the solutions bring their own tests, which should not run twice.\n\
+         #[cfg(test)]\n\
+         pub const SOLUTIONS: &[RegisteredSolution] = &[];\n"
+    );
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("registry.rs");
+    fs::write(out_path, registry).unwrap();
+}
@@ -0,0 +1,97 @@
+use advent_of_code::parse::{self, Span};
+use advent_of_code::template::{Solution, SolutionResult};
+
+advent_of_code::solution!(2023, 2, Day02);
+
+pub struct Day02;
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+pub struct Game {
+    id: u32,
+    /// The most cubes of each color shown at once, in the order of [`COLORS`].
+    max_cubes: [u32; 3],
+}
+
+impl Solution for Day02 {
+    type Parsed = Vec<Game>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> SolutionResult<Self::Parsed> {
+        parse::lines(input)
+            .map(|line| {
+                let (id, rounds) = line.pattern::<(u32, Span)>("Game {}: {}")?;
+                let mut max_cubes = [0; 3];
+
+                for cubes in rounds.split("; ").flat_map(|round| round.split(", ")) {
+                    let (count, color) = cubes.pattern::<(u32, Span)>("{} {}")?;
+                    let index = COLORS
+                        .iter()
+                        .position(|&c| c == color.as_str())
+                        .ok_or_else(|| color.error(format!("unknown color `{color}`")))?;
+
+                    max_cubes[index] = max_cubes[index].max(count);
+                }
+
+                Ok(Game { id, max_cubes })
+            })
+            .collect()
+    }
+
+    fn part_one(games: &Self::Parsed) -> SolutionResult<Option<u32>> {
+        let limits = [12, 13, 14];
+
+        let result = games
+            .iter()
+            .filter(|game| {
+                game.max_cubes
+                    .iter()
+                    .zip(limits)
+                    .all(|(&n, limit)| n <= limit)
+            })
+            .map(|game| game.id)
+            .sum();
+
+        Ok(Some(result))
+    }
+
+    fn part_two(games: &Self::Parsed) -> SolutionResult<Option<u32>> {
+        let result = games
+            .iter()
+            .map(|game| game.max_cubes.iter().product::<u32>())
+            .sum();
+
+        Ok(Some(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let games = Day02::parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
+        let result = Day02::part_one(&games).unwrap();
+        assert_eq!(result, Some(8));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let games = Day02::parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
+        let result = Day02::part_two(&games).unwrap();
+        assert_eq!(result, Some(2286));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day02::parse("Game 1: 3 blue\nGame 2: 4 purple")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 11: unknown color `purple`"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::{HashMap, HashSet};
+
+use advent_of_code::grid::{Grid, Pos};
+use advent_of_code::point::Point;
+
+advent_of_code::solution!(2023, 3);
+
+fn is_symbol(ch: char) -> bool {
+    !ch.is_ascii_digit() && ch != '.'
+}
+
+/// A number in the schematic and the cells around it.
+struct PartNumber {
+    value: u32,
+    neighbours: HashSet<Pos>,
+}
+
+fn part_numbers(grid: &Grid<char>) -> Vec<PartNumber> {
+    let mut numbers = vec![];
+
+    for (y, row) in grid.rows().enumerate() {
+        let mut x = 0;
+
+        while x < row.len() {
+            if !row[x].is_ascii_digit() {
+                x += 1;
+                continue;
+            }
+
+            let start = x;
+            let mut value = 0;
+
+            while let Some(digit) = row.get(x).and_then(|c| c.to_digit(10)) {
+                value = value * 10 + digit;
+                x += 1;
+            }
+
+            let neighbours = (start..x)
+                .flat_map(|x| grid.neighbours8(Point::new(x, y)))
+                .filter(|pos| pos.y != y || !(start..x).contains(&pos.x))
+                .collect();
+
+            numbers.push(PartNumber { value, neighbours });
+        }
+    }
+
+    numbers
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = Grid::parse(input).ok()?;
+
+    let sum = part_numbers(&grid)
+        .iter()
+        .filter(|number| number.neighbours.iter().any(|&pos| is_symbol(grid[pos])))
+        .map(|number| number.value)
+        .sum();
+
+    Some(sum)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let grid = Grid::parse(input).ok()?;
+    let mut gears: HashMap<Pos, Vec<u64>> = HashMap::new();
+
+    for number in part_numbers(&grid) {
+        for &pos in &number.neighbours {
+            if grid[pos] == '*' {
+                gears.entry(pos).or_default().push(number.value as u64);
+            }
+        }
+    }
+
+    let sum = gears
+        .values()
+        .filter(|numbers| numbers.len() == 2)
+        .map(|numbers| numbers[0] * numbers[1])
+        .sum();
+
+    Some(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(4361));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(467835));
+    }
+}
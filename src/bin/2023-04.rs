@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use advent_of_code::parse::{self, Span};
+use advent_of_code::template::{Solution, SolutionResult};
+
+advent_of_code::solution!(2023, 4, Day04);
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    /// The number of winning numbers on each card.
+    type Parsed = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> SolutionResult<Self::Parsed> {
+        parse::lines(input)
+            .map(|line| {
+                let (_, winning, drawn) = line.pattern::<(u32, Span, Span)>("Card {}: {} | {}")?;
+
+                let winning = winning
+                    .split_whitespace()
+                    .map(|n| n.parse())
+                    .collect::<Result<HashSet<u32>, _>>()?;
+
+                let drawn = drawn
+                    .split_whitespace()
+                    .map(|n| n.parse())
+                    .collect::<Result<HashSet<u32>, _>>()?;
+
+                Ok(winning.intersection(&drawn).count() as u32)
+            })
+            .collect()
+    }
+
+    fn part_one(cards: &Self::Parsed) -> SolutionResult<Option<u32>> {
+        let result = cards
+            .iter()
+            .map(|&common_count| {
+                if common_count == 0 {
+                    0
+                } else {
+                    2u32.pow(common_count - 1u32)
+                }
+            })
+            .sum();
+
+        Ok(Some(result))
+    }
+
+    fn part_two(cards: &Self::Parsed) -> SolutionResult<Option<u32>> {
+        // cards are numbered consecutively, so the instances can be tracked by index.
+        let mut instances = vec![1; cards.len()];
+
+        for (game, &common_count) in cards.iter().enumerate() {
+            let won = (game + 1..=game + common_count as usize).filter(|&id| id < cards.len());
+            for game_id in won {
+                instances[game_id] += instances[game];
+            }
+        }
+
+        Ok(Some(instances.iter().sum()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let cards = Day04::parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
+        let result = Day04::part_one(&cards).unwrap();
+        assert_eq!(result, Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let cards = Day04::parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
+        let result = Day04::part_two(&cards).unwrap();
+        assert_eq!(result, Some(30));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day04::parse("Card 1: 41 48 | 83 x").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 20: cannot parse `x`: invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::ops::RangeInclusive;
+
+use advent_of_code::interval::{self, IntervalSet};
+use advent_of_code::parse::{self, ParseError};
+use advent_of_code::template::{Solution, SolutionResult};
+use itertools::Itertools;
+
+advent_of_code::solution!(2023, 5, Day05);
+
+pub struct Day05;
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    /// Every map as a piecewise mapping of source ranges to destination starts.
+    maps: Vec<Vec<(RangeInclusive<u64>, u64)>>,
+}
+
+impl Almanac {
+    fn lowest_location(&self, seeds: IntervalSet<u64>) -> Option<u64> {
+        self.maps
+            .iter()
+            .fold(seeds, |seeds, mapping| seeds.map_piecewise(mapping))
+            .min()
+    }
+}
+
+impl Solution for Day05 {
+    type Parsed = Almanac;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> SolutionResult<Self::Parsed> {
+        let mut sections = parse::sections(input);
+
+        let seeds = match sections.next() {
+            Some(seeds) => seeds.strip_prefix("seeds: ")?.unsigned()?,
+            None => vec![],
+        };
+
+        let maps = sections
+            .map(|map| {
+                map.lines()
+                    .skip(1)
+                    .map(|line| {
+                        let (destination, source, length) =
+                            line.pattern::<(u64, u64, u64)>("{} {} {}")?;
+                        // empty ranges do not map anything.
+                        Ok(interval::range_from_length(source, length)
+                            .map(|source| (source, destination)))
+                    })
+                    .filter_map(Result::transpose)
+                    .collect()
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part_one(almanac: &Self::Parsed) -> SolutionResult<Option<u64>> {
+        let seeds = almanac.seeds.iter().map(|&seed| seed..=seed).collect();
+
+        Ok(almanac.lowest_location(seeds))
+    }
+
+    fn part_two(almanac: &Self::Parsed) -> SolutionResult<Option<u64>> {
+        let seeds = almanac
+            .seeds
+            .iter()
+            .tuples()
+            .filter_map(|(&start, &length)| interval::range_from_length(start, length))
+            .collect();
+
+        Ok(almanac.lowest_location(seeds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let almanac =
+            Day05::parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
+        let result = Day05::part_one(&almanac).unwrap();
+        assert_eq!(result, Some(35));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let almanac =
+            Day05::parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
+        let result = Day05::part_two(&almanac).unwrap();
+        assert_eq!(result, Some(46));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day05::parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 x\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 7: cannot parse `x`: invalid digit found in string"
+        );
+    }
+}
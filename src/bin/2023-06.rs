@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2023, 6);
 
 pub fn part_one(input: &str) -> Option<f32> {
     let (time, distance) = input
@@ -61,13 +61,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(288.0));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(71503.0));
     }
 }
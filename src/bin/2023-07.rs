@@ -1,16 +1,18 @@
-use core::panic;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
+use advent_of_code::parse::{self, Span};
+use advent_of_code::template::{Solution, SolutionResult};
 use itertools::Itertools;
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7, Day07);
+
+pub struct Day07;
 
 #[derive(Debug)]
-struct Hand {
+pub struct Hand {
     cards: Vec<Card>,
     bid: u32,
-    combination: Combination,
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
@@ -68,9 +70,11 @@ impl Card {
     }
 }
 
-impl From<char> for Card {
-    fn from(value: char) -> Self {
-        match value {
+impl TryFrom<char> for Card {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
             'A' => Self::Ace,
             'K' => Self::King,
             'Q' => Self::Queen,
@@ -84,8 +88,8 @@ impl From<char> for Card {
             '4' => Self::Four,
             '3' => Self::Three,
             '2' => Self::Two,
-            _ => panic!("Unexpected charecter for card"),
-        }
+            _ => return Err(format!("unexpected character `{value}` for card")),
+        })
     }
 }
 
@@ -138,104 +142,81 @@ fn count_occurence(hand: &[Card]) -> Vec<(Card, u8)> {
         .collect()
 }
 
-fn parse_hand(value: &str) -> Hand {
-    let (cards, bid) = value.split_once(' ').expect("Should be valid hand");
-    let bid = bid.parse().expect("should be a number");
-    let cards: Vec<Card> = cards.chars().map(|ch| ch.into()).collect();
-    let counts = count_occurence(cards.as_slice());
-    let combination: Combination = counts
-        .iter()
-        .map(|(_, count)| count.to_owned())
-        .collect::<Vec<u8>>()
-        .as_slice()
-        .into();
-
-    Hand {
-        cards,
-        bid,
-        combination,
-    }
-}
-
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut hands: Vec<Hand> = input.lines().map(parse_hand).collect();
-    hands.sort_by(|a, b| match a.combination.cmp(&b.combination) {
-        Ordering::Equal => {
-            let tuples = a.cards.iter().interleave(b.cards.iter()).tuples();
-            for (a, b) in tuples {
-                match a.value(NORMAL_ORDERING).cmp(&b.value(NORMAL_ORDERING)) {
-                    Ordering::Equal => continue,
-                    other => return other,
-                }
-            }
-
-            Ordering::Equal
-        }
-        other => other,
-    });
-
-    Some(
-        hands
-            .iter()
-            .enumerate()
-            .map(|(index, hand)| hand.bid * (index as u32 + 1))
-            .sum(),
-    )
-}
-
-fn parse_joker_hand(value: &str) -> Hand {
-    let (cards, bid) = value.split_once(' ').expect("Should be valid hand");
-    let bid = bid.parse().expect("should be a number");
-    let cards: Vec<Card> = cards.chars().map(|ch| ch.into()).collect();
-    let mut counts = count_occurence(cards.as_slice());
-
+fn combination(cards: &[Card], with_jokers: bool) -> Combination {
+    let mut counts = count_occurence(cards);
     let unique = counts.len();
 
-    if unique > 1 {
+    if with_jokers && unique > 1 {
         if let Some(position) = counts.iter().position(|(card, _)| card == &Card::Jack) {
             let joker_count = counts.remove(position);
             counts[unique - 2].1 += joker_count.1;
         }
     }
 
-    let combination: Combination = counts
+    counts
         .iter()
         .map(|(_, count)| count.to_owned())
         .collect::<Vec<u8>>()
         .as_slice()
-        .into();
-
-    Hand {
-        cards,
-        bid,
-        combination,
-    }
+        .into()
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut hands: Vec<Hand> = input.lines().map(parse_joker_hand).collect();
-    hands.sort_by(|a, b| match a.combination.cmp(&b.combination) {
-        Ordering::Equal => {
-            let tuples = a.cards.iter().interleave(b.cards.iter()).tuples();
-            for (a, b) in tuples {
-                match a.value(JOKER_ORDERING).cmp(&b.value(JOKER_ORDERING)) {
-                    Ordering::Equal => continue,
-                    other => return other,
+fn total_winnings(hands: &[Hand], ordering: [Card; 13], with_jokers: bool) -> u32 {
+    let mut hands: Vec<(Combination, &Hand)> = hands
+        .iter()
+        .map(|hand| (combination(&hand.cards, with_jokers), hand))
+        .collect();
+
+    hands.sort_by(|(a_combination, a), (b_combination, b)| {
+        match a_combination.cmp(b_combination) {
+            Ordering::Equal => {
+                let tuples = a.cards.iter().interleave(b.cards.iter()).tuples();
+                for (a, b) in tuples {
+                    match a.value(ordering).cmp(&b.value(ordering)) {
+                        Ordering::Equal => continue,
+                        other => return other,
+                    }
                 }
-            }
 
-            Ordering::Equal
+                Ordering::Equal
+            }
+            other => other,
         }
-        other => other,
     });
 
-    Some(
-        hands
-            .iter()
-            .enumerate()
-            .map(|(index, hand)| hand.bid * (index as u32 + 1))
-            .sum(),
-    )
+    hands
+        .iter()
+        .enumerate()
+        .map(|(index, (_, hand))| hand.bid * (index as u32 + 1))
+        .sum()
+}
+
+impl Solution for Day07 {
+    type Parsed = Vec<Hand>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> SolutionResult<Self::Parsed> {
+        parse::lines(input)
+            .map(|line| {
+                let (cards, bid) = line.pattern::<(Span, u32)>("{} {}")?;
+                let cards = cards
+                    .chars()
+                    .map(|(c, span)| Card::try_from(c).map_err(|e| span.error(e)))
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Hand { cards, bid })
+            })
+            .collect()
+    }
+
+    fn part_one(hands: &Self::Parsed) -> SolutionResult<Option<u32>> {
+        Ok(Some(total_winnings(hands, NORMAL_ORDERING, false)))
+    }
+
+    fn part_two(hands: &Self::Parsed) -> SolutionResult<Option<u32>> {
+        Ok(Some(total_winnings(hands, JOKER_ORDERING, true)))
+    }
 }
 
 #[cfg(test)]
@@ -244,13 +225,24 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let hands = Day07::parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
+        let result = Day07::part_one(&hands).unwrap();
         assert_eq!(result, Some(6440));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let hands = Day07::parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
+        let result = Day07::part_two(&hands).unwrap();
         assert_eq!(result, Some(5905));
     }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day07::parse("32T3K 765\nKKX77 28").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: unexpected character `X` for card"
+        );
+    }
 }
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use advent_of_code::math::crt;
+use itertools::Itertools;
+
+advent_of_code::solution!(2023, 8);
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let paren: &[char] = &['(', ')'];
+    let (moves, maps) = input.split_once("\n\n").unwrap();
+
+    let mut network: HashMap<&str, (&str, &str)> = HashMap::new();
+    maps.lines().for_each(|line| {
+        let (key, pair) = line.split_once(" = ").unwrap();
+        let value = pair.trim_matches(paren).split_once(", ").unwrap();
+        network.insert(key, value);
+    });
+
+    let mut move_count = 0u32;
+    let mut current = "AAA";
+
+    let mut iter = moves.chars();
+
+    while current != "ZZZ" {
+        if let Some(m) = iter.next() {
+            let node = network.get(current).unwrap();
+            match m {
+                'L' => current = node.0,
+                'R' => current = node.1,
+                _ => panic!("invalid move"),
+            }
+
+            move_count += 1;
+        } else {
+            iter = moves.chars();
+        }
+    }
+
+    Some(move_count)
+}
+
+/// The walk of a ghost, which ends up in a cycle as there are only so many pairs of node and position in the moves.
+struct Cycle {
+    /// The step at which the cycle starts.
+    start: u64,
+    length: u64,
+    /// The steps up to the end of the first round of the cycle at which the ghost is on a node ending in `Z`.
+    hits: Vec<u64>,
+}
+
+impl Cycle {
+    fn find(moves: &str, network: &HashMap<&str, (&str, &str)>, start: &str) -> Self {
+        let moves: Vec<char> = moves.chars().collect();
+        let mut seen: HashMap<(&str, usize), u64> = HashMap::new();
+        let mut hits = vec![];
+        let mut node = start;
+
+        for step in 0.. {
+            let index = step as usize % moves.len();
+
+            if let Some(&first) = seen.get(&(node, index)) {
+                return Self {
+                    start: first,
+                    length: step - first,
+                    hits,
+                };
+            }
+            seen.insert((node, index), step);
+
+            if node.ends_with('Z') {
+                hits.push(step);
+            }
+
+            let (left, right) = network.get(node).unwrap();
+            node = match moves[index] {
+                'L' => left,
+                'R' => right,
+                _ => panic!("invalid move"),
+            };
+        }
+
+        unreachable!()
+    }
+
+    fn is_hit(&self, step: u64) -> bool {
+        let step = if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        };
+        self.hits.contains(&step)
+    }
+
+    /// The hits inside the cycle as `(remainder, modulus)`, they repeat every `length` steps.
+    fn congruences(&self) -> Vec<(i64, i64)> {
+        self.hits
+            .iter()
+            .filter(|&&hit| hit >= self.start)
+            .map(|&hit| (hit as i64, self.length as i64))
+            .collect()
+    }
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let paren: &[char] = &['(', ')'];
+    let (moves, maps) = input.split_once("\n\n").unwrap();
+
+    let mut network: HashMap<&str, (&str, &str)> = HashMap::new();
+    let mut starts: Vec<&str> = vec![];
+    maps.lines().for_each(|line| {
+        let (key, pair) = line.split_once(" = ").unwrap();
+        let value = pair.trim_matches(paren).split_once(", ").unwrap();
+        network.insert(key, value);
+        if key.ends_with('A') {
+            starts.push(key);
+        }
+    });
+
+    let cycles: Vec<Cycle> = starts
+        .iter()
+        .map(|start| Cycle::find(moves, &network, start))
+        .collect();
+
+    // until every ghost is inside its cycle, the hits do not repeat yet.
+    let offset = cycles.iter().map(|cycle| cycle.start).max()?;
+    if let Some(step) = (0..offset).find(|&step| cycles.iter().all(|cycle| cycle.is_hit(step))) {
+        return Some(step);
+    }
+
+    // afterwards, every combination of hits is a system of congruences.
+    cycles
+        .iter()
+        .map(Cycle::congruences)
+        .multi_cartesian_product()
+        .filter_map(crt)
+        .map(|(remainder, modulus)| {
+            let (remainder, modulus) = (remainder as u64, modulus as u64);
+            if remainder >= offset {
+                remainder
+            } else {
+                remainder + (offset - remainder).div_ceil(modulus) * modulus
+            }
+        })
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+}
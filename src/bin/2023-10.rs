@@ -0,0 +1,177 @@
+use advent_of_code::grid::{Grid, Pos};
+use advent_of_code::point::{Direction, Point};
+use advent_of_code::search::{bfs, flood_fill};
+use advent_of_code::template::{Solution, SolutionResult};
+
+advent_of_code::solution!(2023, 10, Day10);
+
+/// The bit of a pipe's adjacency mask that connects it in a direction, NESW from the highest bit.
+fn connection(dir: Direction) -> u16 {
+    match dir {
+        Direction::North => 0b1000,
+        Direction::East => 0b0100,
+        Direction::South => 0b0010,
+        Direction::West => 0b0001,
+        _ => 0,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pipe {
+    /// `S`, connects in every direction.
+    Start,
+    /// `.`
+    Ground,
+    /// `|`
+    Vertical,
+    /// `-`
+    Horizontal,
+    /// `L`
+    NorthEast,
+    /// `F`
+    SouthEast,
+    /// `7`
+    SouthWest,
+    /// `J`
+    NorthWest,
+}
+
+impl Pipe {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'S' => Some(Pipe::Start),
+            '.' => Some(Pipe::Ground),
+            '|' => Some(Pipe::Vertical),
+            '-' => Some(Pipe::Horizontal),
+            'L' => Some(Pipe::NorthEast),
+            'F' => Some(Pipe::SouthEast),
+            '7' => Some(Pipe::SouthWest),
+            'J' => Some(Pipe::NorthWest),
+            _ => None,
+        }
+    }
+
+    fn adjacency(self) -> u16 {
+        match self {
+            Pipe::Start => 0b1111,
+            Pipe::Ground => 0b0000,
+            Pipe::Vertical => 0b1010,
+            Pipe::Horizontal => 0b0101,
+            Pipe::NorthEast => 0b1100,
+            Pipe::SouthEast => 0b0110,
+            Pipe::SouthWest => 0b0011,
+            Pipe::NorthWest => 0b1001,
+        }
+    }
+}
+
+fn is_adjacent(a: Pipe, b: Pipe, dir: Direction) -> bool {
+    a.adjacency() & connection(dir) != 0 && b.adjacency() & connection(dir.opposite()) != 0
+}
+
+pub struct Pipes {
+    tiles: Grid<Pipe>,
+    start: Pos,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Pipes;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> SolutionResult<Pipes> {
+        let tiles = Grid::parse_with(input, Pipe::from_char)?;
+        let start = tiles.find(&Pipe::Start).ok_or("should have a start tile")?;
+
+        Ok(Pipes { tiles, start })
+    }
+
+    fn part_one(pipes: &Pipes) -> SolutionResult<Option<u32>> {
+        Ok(part_one(pipes))
+    }
+
+    fn part_two(pipes: &Pipes) -> SolutionResult<Option<u32>> {
+        Ok(part_two(pipes))
+    }
+}
+
+/// Returns the pipes connected to the one at `pos`.
+fn connected(tiles: &Grid<Pipe>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    Direction::CARDINAL.into_iter().filter_map(move |dir| {
+        let next = tiles.step(pos, dir)?;
+        is_adjacent(tiles[pos], tiles[next], dir).then_some(next)
+    })
+}
+
+fn part_one(pipes: &Pipes) -> Option<u32> {
+    let Pipes { tiles, start } = pipes;
+
+    let search = bfs(*start, |&pos| connected(tiles, pos));
+    let max = search.distances.into_values().max()?;
+
+    Some(max as u32)
+}
+
+fn part_two(pipes: &Pipes) -> Option<u32> {
+    let Pipes {
+        tiles: pipes,
+        start,
+    } = pipes;
+    let (width, height) = (pipes.width(), pipes.height());
+
+    let visited = flood_fill([*start], |&pos| connected(pipes, pos));
+
+    let mut enclosed = 0u32;
+
+    for (y, row) in pipes.rows().enumerate() {
+        for x in 0..row.len() {
+            let current = Point::new(x, y);
+            if visited.contains(&current) {
+                continue;
+            }
+
+            let mut edges = 0;
+
+            let mut x2 = x;
+            let mut y2 = y;
+
+            while x2 < width && y2 < height {
+                let current = Point::new(x2, y2);
+                let pipe = pipes[current];
+                if visited.contains(&current) && pipe != Pipe::NorthEast && pipe != Pipe::SouthWest
+                {
+                    edges += 1;
+                }
+
+                x2 += 1;
+                y2 += 1;
+            }
+            if edges % 2 == 1 {
+                enclosed += 1;
+            }
+        }
+    }
+
+    Some(enclosed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let pipes = Day10::parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
+        let result = Day10::part_one(&pipes).unwrap();
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let pipes = Day10::parse(&advent_of_code::template::read_file("examples", PUZZLE)).unwrap();
+        let result = Day10::part_two(&pipes).unwrap();
+        assert_eq!(result, None);
+    }
+}
@@ -0,0 +1,113 @@
+use std::{collections::HashSet, ops::Range};
+
+use advent_of_code::grid::{Grid, Pos};
+use colored::ColoredString;
+use itertools::Itertools;
+
+advent_of_code::solution!(2023, 11);
+
+fn ranges(a: &Pos, b: &Pos) -> (Range<usize>, Range<usize>) {
+    let x = if a.x < b.x { a.x..b.x } else { b.x..a.x };
+    let y = if a.y < b.y { a.y..b.y } else { b.y..a.y };
+    (x, y)
+}
+
+fn _debug_space<P>(universe: &str, predicate: P)
+where
+    P: Fn(usize, usize, char) -> ColoredString,
+{
+    for (y, row) in universe.lines().enumerate() {
+        print!("{y:>3}: ");
+        for (x, cell) in row.chars().enumerate() {
+            print!(
+                "{}",
+                predicate(x, y, cell));
+        }
+        println!();
+    }
+    println!();
+    println!();
+}
+
+/// Returns the galaxies and the indices of the empty columns and rows.
+fn universe(input: &str) -> Option<(Vec<Pos>, HashSet<usize>, HashSet<usize>)> {
+    let grid = Grid::parse(input).ok()?;
+
+    let galaxies = grid
+        .iter()
+        .filter(|(_, &cell)| cell == '#')
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let cols = grid
+        .columns()
+        .enumerate()
+        .filter_map(|(x, mut col)| col.all(|&cell| cell != '#').then_some(x))
+        .collect();
+
+    let rows = grid
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| !row.contains(&'#'))
+        .map(|(y, _)| y)
+        .collect();
+
+    Some((galaxies, cols, rows))
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (galaxies, cols, rows) = universe(input)?;
+
+    let result = galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| {
+            let (rx, ry) = ranges(a, b);
+            let col = cols.iter().filter(|x| rx.contains(x)).count();
+            let row = rows.iter().filter(|y| ry.contains(y)).count();
+
+            let c = a.manhattan(*b) + col + row;
+
+            c as u32
+        })
+        .sum();
+
+    Some(result)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let (galaxies, cols, rows) = universe(input)?;
+
+    let result = galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| {
+            let (rx, ry) = ranges(a, b);
+            let col = cols.iter().filter(|x| rx.contains(x)).count() * 999_999;
+            let row = rows.iter().filter(|y| ry.contains(y)).count() * 999_999;
+
+            let c = a.manhattan(*b) + col + row;
+
+            c as u64
+        })
+        .sum();
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(374));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+}
@@ -114,6 +114,103 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// An inclusive range of days, e.g. `3..=7`.
+///
+/// Parses from `3..=7`, `3..8`, `3..`, `..=7`, `..8` or a single day such as `5`.
+///
+/// ```
+/// # use advent_of_code::{Day, DayRange};
+/// let range: DayRange = "3..=7".parse().unwrap();
+/// assert!(range.contains(Day::new(7).unwrap()));
+/// assert_eq!(range.days().count(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DayRange {
+    start: Day,
+    end: Day,
+}
+
+impl DayRange {
+    /// Creates a [`DayRange`] from `start` to `end`, both inclusive.
+    /// Returns [`None`] if the range is empty.
+    pub fn new(start: Day, end: Day) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// The range of all days of advent.
+    pub fn all() -> Self {
+        Self {
+            start: Day(1),
+            end: Day(25),
+        }
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.start <= day && day <= self.end
+    }
+
+    /// Returns an iterator over the days in the range.
+    pub fn days(&self) -> impl Iterator<Item = Day> {
+        (self.start.0..=self.end.0).map(Day)
+    }
+}
+
+impl Display for DayRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}..={}", self.start, self.end)
+        }
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = DayRangeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((start, end)) = s.split_once("..") else {
+            let day = s.parse().map_err(|_| DayRangeFromStrError)?;
+            return Ok(Self {
+                start: day,
+                end: day,
+            });
+        };
+
+        let start = match start {
+            "" => Day(1),
+            start => start.parse().map_err(|_| DayRangeFromStrError)?,
+        };
+
+        let end = match end.strip_prefix('=') {
+            Some(end) => end.parse().map_err(|_| DayRangeFromStrError)?,
+            None if end.is_empty() => Day(25),
+            // exclusive ends may be one past the last day, e.g. `1..26`.
+            None => end
+                .parse::<u8>()
+                .ok()
+                .and_then(|end| Day::new(end.checked_sub(1)?))
+                .ok_or(DayRangeFromStrError)?,
+        };
+
+        Self::new(start, end).ok_or(DayRangeFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`DayRange`].
+#[derive(Debug)]
+pub struct DayRangeFromStrError;
+
+impl Error for DayRangeFromStrError {}
+
+impl Display for DayRangeFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a non-empty range of days between 1 and 25, e.g. `3..=7`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -134,7 +231,7 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DayRange};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +264,24 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn parses_day_ranges() {
+        let range = |start, end| DayRange::new(Day(start), Day(end)).unwrap();
+
+        assert_eq!("3..=7".parse::<DayRange>().unwrap(), range(3, 7));
+        assert_eq!("3..8".parse::<DayRange>().unwrap(), range(3, 7));
+        assert_eq!("20..".parse::<DayRange>().unwrap(), range(20, 25));
+        assert_eq!("..=5".parse::<DayRange>().unwrap(), range(1, 5));
+        assert_eq!("1..26".parse::<DayRange>().unwrap(), DayRange::all());
+        assert_eq!("10".parse::<DayRange>().unwrap(), range(10, 10));
+
+        assert!("7..=3".parse::<DayRange>().is_err());
+        assert!("3..3".parse::<DayRange>().is_err());
+        assert!("0..=3".parse::<DayRange>().is_err());
+        assert!("1..=26".parse::<DayRange>().is_err());
+        assert!("a..b".parse::<DayRange>().is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,397 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::point::{Direction, Point};
+
+/// The position of a cell, with `(0, 0)` in the top left corner.
+pub type Pos = Point<usize>;
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// ```
+/// # use advent_of_code::{grid::Grid, point::Point};
+/// let grid = Grid::parse("#.\n.S").unwrap();
+/// assert_eq!(grid.find(&'S'), Some(Point::new(1, 1)));
+/// assert_eq!(grid.get(Point::new(2, 0)), None);
+/// assert_eq!(grid.neighbours4(Point::new(0, 0)).count(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a [`Grid`] from cells in row order,
+    /// returns [`None`] if their number does not match the dimensions.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        (cells.len() == width * height).then_some(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Creates a [`Grid`] with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses a grid from the lines of a puzzle input, converting every character with `f`.
+    /// Fails if `f` returns [`None`] for a character or if the lines have different lengths.
+    pub fn parse_with(
+        input: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, GridError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let mut length = 0;
+
+            for (x, c) in line.chars().enumerate() {
+                let cell = f(c).ok_or(GridError::InvalidCell { x, y, cell: c })?;
+                cells.push(cell);
+                length += 1;
+            }
+
+            match width {
+                None => width = Some(length),
+                Some(width) if width != length => {
+                    return Err(GridError::Ragged {
+                        y,
+                        length,
+                        expected: width,
+                    })
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.ok_or(GridError::Empty)?,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at a position, or [`None`] if it is out of bounds.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    /// Returns the cell at a position, or [`None`] if it is out of bounds.
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    /// Returns the position one step in `direction` from `pos`, or [`None`] if it is out of bounds.
+    ///
+    /// ```
+    /// # use advent_of_code::{grid::Grid, point::{Direction, Point}};
+    /// let grid = Grid::filled(3, 3, '.');
+    /// assert_eq!(grid.step(Point::new(1, 0), Direction::SouthEast), Some(Point::new(2, 1)));
+    /// assert_eq!(grid.step(Point::new(1, 0), Direction::North), None);
+    /// ```
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        pos.checked_step(direction)
+            .filter(|&pos| self.contains(pos))
+    }
+
+    /// Returns the orthogonal neighbours of a position that are in bounds, clockwise starting from north.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// Returns the orthogonal and diagonal neighbours of a position that are in bounds, clockwise starting from north.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// Returns all cells together with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Point::new(i % width, i / width), cell))
+    }
+
+    /// Returns the position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find_map(|(pos, cell)| (cell == value).then_some(pos))
+    }
+
+    /// Returns the cells of a row, or [`None`] if it is out of bounds.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Returns the cells of a column, or [`None`] if it is out of bounds.
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of zero, which an empty grid would have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// Converts every cell with `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Returns the grid mirrored along its main diagonal, i.e. rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| Point::new(y, x))
+    }
+
+    /// Returns the grid rotated by 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| {
+            Point::new(y, self.height - 1 - x)
+        })
+    }
+
+    /// Returns the grid rotated by 90 degrees counterclockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| {
+            Point::new(self.width - 1 - y, x)
+        })
+    }
+
+    /// Builds a grid of the given dimensions, taking the cell at `(x, y)` from the position returned by `source`.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos).then_some(pos.y * self.width + pos.x)
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of characters from the lines of a puzzle input.
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        Self::parse_with(input, Some)
+    }
+}
+
+/// Panics if the position is out of bounds, use [`Grid::get`] to handle that case.
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos).expect("position should be in bounds")
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos).expect("position should be in bounds")
+    }
+}
+
+/// Displays the grid row by row, like the puzzle input it was parsed from.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when parsing a [`Grid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// The input has no lines.
+    Empty,
+    /// A line has a different length than the first one.
+    Ragged {
+        y: usize,
+        length: usize,
+        expected: usize,
+    },
+    /// A character could not be converted into a cell.
+    InvalidCell { x: usize, y: usize, cell: char },
+}
+
+impl Error for GridError {}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => f.write_str("expecting at least one line"),
+            GridError::Ragged {
+                y,
+                length,
+                expected,
+            } => write!(
+                f,
+                "line {} has {length} cells, expecting {expected} like the first line",
+                y + 1
+            ),
+            GridError::InvalidCell { x, y, cell } => {
+                write!(
+                    f,
+                    "invalid cell `{cell}` at line {}, column {}",
+                    y + 1,
+                    x + 1
+                )
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, GridError};
+    use crate::point::Point;
+
+    const INPUT: &str = "abc\ndef";
+
+    #[test]
+    fn parses_input() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(3, 1)), None);
+        assert_eq!(grid[Point::new(1, 0)], 'b');
+        assert_eq!(grid.to_string(), INPUT);
+
+        assert_eq!(Grid::parse(""), Err(GridError::Empty));
+        assert_eq!(
+            Grid::parse("ab\nc"),
+            Err(GridError::Ragged {
+                y: 1,
+                length: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            Grid::parse_with("12\n3x", |c| c.to_digit(10))
+                .unwrap_err()
+                .to_string(),
+            "invalid cell `x` at line 2, column 2"
+        );
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = Grid::filled(3, 3, 0);
+
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8(Point::new(2, 2)).collect::<Vec<_>>(),
+            vec![Point::new(2, 1), Point::new(1, 2), Point::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(
+            grid.column(1).unwrap().collect::<String>(),
+            "be".to_string()
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(grid.find(&'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+    }
+
+    #[test]
+    fn transforms_grid() {
+        let grid = Grid::parse(INPUT).unwrap();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+}
@@ -0,0 +1,365 @@
+use std::ops::RangeInclusive;
+
+use num_traits::PrimInt;
+
+/// Set operations on single inclusive ranges.
+pub trait InclusiveRangeExt
+where
+    Self: Sized,
+{
+    type Index;
+
+    fn overlaps(&self, other: &Self) -> bool;
+    /// Returns the part of `self` that is also part of `other`.
+    fn get_overlap(&self, other: &Self) -> Option<Self>;
+    /// Returns the parts of `self` that are not part of `other`.
+    fn get_rest(&self, other: &Self) -> Vec<Self>;
+    fn offset(&self, offset: Self::Index) -> Self;
+}
+
+impl<T: PrimInt> InclusiveRangeExt for RangeInclusive<T> {
+    type Index = T;
+
+    fn overlaps(&self, other: &Self) -> bool {
+        other.end() >= self.start() && self.end() >= other.start()
+    }
+
+    fn get_overlap(&self, other: &Self) -> Option<Self> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        let start = *self.start().max(other.start());
+        let end = *self.end().min(other.end());
+        Some(start..=end)
+    }
+
+    fn get_rest(&self, other: &Self) -> Vec<Self> {
+        if !self.overlaps(other) {
+            return vec![self.clone()];
+        }
+
+        let mut result = vec![];
+
+        if self.start() < other.start() {
+            result.push(*self.start()..=(*other.start() - T::one()));
+        }
+
+        if self.end() > other.end() {
+            result.push((*other.end() + T::one())..=*self.end());
+        }
+
+        result
+    }
+
+    fn offset(&self, offset: T) -> Self {
+        (*self.start() + offset)..=(*self.end() + offset)
+    }
+}
+
+/// Returns the range of `length` values that starts at `start`.
+/// Returns [`None`] if the range is empty or its end does not fit into `T`.
+///
+/// ```
+/// # use advent_of_code::interval;
+/// assert_eq!(interval::range_from_length(98_u64, 2), Some(98..=99));
+/// assert_eq!(interval::range_from_length(0_u64, 0), None);
+/// ```
+pub fn range_from_length<T: PrimInt>(start: T, length: T) -> Option<RangeInclusive<T>> {
+    if length <= T::zero() {
+        return None;
+    }
+
+    let end = start.checked_add(&(length - T::one()))?;
+    Some(start..=end)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A set of integers, stored as sorted ranges that neither overlap nor touch.
+///
+/// ```
+/// # use advent_of_code::interval::IntervalSet;
+/// let set: IntervalSet<u32> = [1..=3, 4..=5, 10..=12].into_iter().collect();
+/// assert_eq!(set.ranges(), &[1..=5, 10..=12]);
+///
+/// // map 0..=4 to 100..=104 and keep everything else.
+/// let mapped = set.map_piecewise(&[(0..=4, 100)]);
+/// assert_eq!(mapped.ranges(), &[5..=5, 10..=12, 101..=104]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// The ranges of the set in ascending order.
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| *range.start())
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| *range.end())
+    }
+
+    /// Returns the number of values in the set.
+    /// Note that this overflows `T` if the set contains all of its values.
+    pub fn count(&self) -> T {
+        self.ranges.iter().fold(T::zero(), |count, range| {
+            count + (*range.end() - *range.start()) + T::one()
+        })
+    }
+
+    /// Adds a range, merging it with overlapping and adjacent ranges. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        self.ranges.push(range);
+        self.normalize();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            if let Some(overlap) = a.get_overlap(b) {
+                ranges.push(overlap);
+            }
+
+            // advance whichever range ends first, it cannot overlap anything else.
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let ranges = self
+            .ranges
+            .iter()
+            .flat_map(|range| {
+                other
+                    .ranges
+                    .iter()
+                    .filter(|x| x.overlaps(range))
+                    .fold(vec![range.clone()], |pieces, x| {
+                        pieces.iter().flat_map(|piece| piece.get_rest(x)).collect()
+                    })
+            })
+            .collect();
+
+        Self { ranges }
+    }
+
+    /// Applies a piecewise mapping: values in a `source` range move to the same position relative to its `destination` start,
+    /// all other values are kept as they are. Ranges of the set that span several sources are split accordingly.
+    /// If sources overlap, the first one wins.
+    pub fn map_piecewise(&self, mapping: &[(RangeInclusive<T>, T)]) -> Self {
+        let mut rest = self.clone();
+        let mut mapped = Self::new();
+
+        for (source, destination) in mapping {
+            let source_set = Self::from(source.clone());
+
+            for range in rest.intersection(&source_set).ranges {
+                let start = *range.start() - *source.start() + *destination;
+                let end = *range.end() - *source.start() + *destination;
+                mapped.ranges.push(start..=end);
+            }
+
+            rest = rest.difference(&source_set);
+        }
+
+        mapped.ranges.extend(rest.ranges);
+        mapped.normalize();
+        mapped
+    }
+
+    /// Sorts the ranges and merges the ones that overlap or touch.
+    fn normalize(&mut self) {
+        self.ranges.retain(|range| !range.is_empty());
+        self.ranges.sort_by_key(|range| *range.start());
+
+        let mut merged: Vec<RangeInclusive<T>> = Vec::with_capacity(self.ranges.len());
+
+        for range in self.ranges.drain(..) {
+            match merged.last_mut() {
+                Some(last)
+                    if last
+                        .end()
+                        .checked_add(&T::one())
+                        .is_none_or(|next| *range.start() <= next) =>
+                {
+                    let end = *last.end().max(range.end());
+                    *last = *last.start()..=end;
+                }
+                _ => merged.push(range),
+            }
+        }
+
+        self.ranges = merged;
+    }
+}
+
+impl<T: PrimInt> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PrimInt> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: PrimInt> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self {
+            ranges: iter.into_iter().collect(),
+        };
+        set.normalize();
+        set
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use rstest::rstest;
+    use std::ops::RangeInclusive;
+
+    use super::{range_from_length, InclusiveRangeExt, IntervalSet};
+
+    #[rstest]
+    #[case(50..=55, 50..=55, true)] // equal
+    #[case(50..=55, 53..=56, true)] // overlap after
+    #[case(50..=55, 52..=54, true)] // within
+    #[case(50..=55, 48..=51, true)] // overlap before
+    #[case(50..=55, 48..=56, true)] // around
+    #[case(50..=55, 32..=46, false)] // outside before
+    #[case(50..=55, 72..=78, false)] // outside after
+    fn test_ranges(
+        #[case] first: RangeInclusive<u32>,
+        #[case] second: RangeInclusive<u32>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(first.overlaps(&second), expected);
+    }
+
+    #[rstest]
+    #[case(50..=55, 50..=55, Some(50..=55))] // equal
+    #[case(50..=55, 53..=56, Some(53..=55))] // overlap after
+    #[case(50..=55, 52..=54, Some(52..=54))] // within
+    #[case(50..=55, 48..=51, Some(50..=51))] // overlap before
+    #[case(50..=55, 48..=56, Some(50..=55))] // around
+    #[case(50..=55, 32..=46, None)] // outside before
+    #[case(50..=55, 72..=78, None)] // outside after
+    fn test_get_overlap(
+        #[case] first: RangeInclusive<u32>,
+        #[case] second: RangeInclusive<u32>,
+        #[case] expected: Option<RangeInclusive<u32>>,
+    ) {
+        assert_eq!(first.get_overlap(&second), expected);
+    }
+
+    #[rstest]
+    #[case(50..=55, 50..=55, vec![])] // equal
+    #[case(50..=55, 53..=56, vec![50..=52])] // overlap after
+    #[case(50..=57, 53..=55, vec![50..=52, 56..=57])] // within
+    #[case(50..=57, 48..=51, vec![52..=57])] // overlap before
+    #[case(50..=55, 48..=56, vec![])] // around
+    #[case(50..=55, 32..=46, vec![50..=55])] // outside before
+    #[case(50..=55, 72..=78, vec![50..=55])] // outside after
+    fn test_get_rest(
+        #[case] first: RangeInclusive<u32>,
+        #[case] second: RangeInclusive<u32>,
+        #[case] expected: Vec<RangeInclusive<u32>>,
+    ) {
+        assert_eq!(first.get_rest(&second), expected);
+    }
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn merges_ranges() {
+        let mut intervals = set(&[10..=12, 1..=3, 2..=5, 6..=6]);
+        assert_eq!(intervals.ranges(), &[1..=6, 10..=12]);
+        assert_eq!(intervals.count(), 9);
+
+        intervals.insert(7..=9);
+        assert_eq!(intervals.ranges(), &[1..=12]);
+
+        let full: IntervalSet<u8> = [0..=200, 201..=255].into_iter().collect();
+        assert_eq!(full.ranges(), &[0..=255]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[1..=5, 10..=15]);
+        let b = set(&[4..=11, 20..=21]);
+
+        assert_eq!(a.union(&b).ranges(), &[1..=15, 20..=21]);
+        assert_eq!(a.intersection(&b).ranges(), &[4..=5, 10..=11]);
+        assert_eq!(a.difference(&b).ranges(), &[1..=3, 12..=15]);
+        assert_eq!(b.difference(&a).ranges(), &[6..=9, 20..=21]);
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn maps_piecewise() {
+        // the first map of the 2023 day 5 example.
+        let seeds = set(&[79..=92, 55..=67]);
+        let mapped = seeds.map_piecewise(&[(98..=99, 50), (50..=97, 52)]);
+        assert_eq!(mapped.ranges(), &[57..=69, 81..=94]);
+
+        let split = set(&[0..=9]).map_piecewise(&[(3..=4, 100), (8..=20, 0)]);
+        assert_eq!(split.ranges(), &[0..=2, 5..=7, 100..=101]);
+        assert_eq!(split.min(), Some(0));
+        assert_eq!(split.max(), Some(101));
+    }
+
+    #[test]
+    fn skips_empty_ranges() {
+        assert_eq!(range_from_length(0_u64, 0), None);
+        assert_eq!(range_from_length(5_u64, 0), None);
+        assert_eq!(range_from_length(-2, 3), Some(-2..=0));
+        assert_eq!(range_from_length(u8::MAX, 2), None);
+
+        // a map with an empty range starting at 0 keeps every value.
+        let mapping: Vec<_> = [(0_u64, 0, 50), (10, 1, 100)]
+            .into_iter()
+            .filter_map(|(source, length, destination)| {
+                range_from_length(source, length).map(|source| (source, destination))
+            })
+            .collect();
+        let mapped = IntervalSet::from(0..=11_u64).map_piecewise(&mapping);
+        assert_eq!(mapped.ranges(), &[0..=9, 11..=11, 100..=100]);
+    }
+}
@@ -1,4 +1,15 @@
 mod day;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+mod puzzle;
+pub mod search;
 pub mod template;
+mod year;
 
 pub use day::*;
+pub use puzzle::*;
+pub use year::*;
@@ -1,55 +1,141 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{all, download, read, scaffold, solve, verify};
+use advent_of_code::template::registry::Registry;
 use args::{parse, AppArguments};
 
+#[cfg(feature = "registry")]
+mod registry {
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
+
+/// Returns the solutions linked into this binary, see [`advent_of_code::template::registry`].
+fn get_registry() -> Option<Registry> {
+    #[cfg(feature = "registry")]
+    return Some(registry::SOLUTIONS);
+
+    #[cfg(not(feature = "registry"))]
+    None
+}
+
 mod args {
     use std::process;
 
-    use advent_of_code::Day;
+    use advent_of_code::template::{bench_history, commands::all};
+    use advent_of_code::{Day, DayRange, PuzzleId, Year};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
             time: bool,
+            budget: Option<u64>,
+            timeout: Option<u64>,
             submit: Option<u8>,
+            wait: bool,
         },
-        All {
+        All(all::AllOptions),
+        Verify {
+            year: Year,
+            day: Option<Day>,
             release: bool,
-            time: bool,
         },
     }
 
+    /// Reads the `--year` option, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env()
+                .ok_or_else(|| "no year specified, pass `--year` or set `AOC_YEAR`.".into()),
+        }
+    }
+
+    fn parse_puzzle(
+        args: &mut pico_args::Arguments,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        let day: Day = args.free_from_str()?;
+        Ok(PuzzleId::new(year, day))
+    }
+
+    /// Reads the `--compare` flag together with its `--baseline` and `--threshold` options.
+    fn parse_compare(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<all::CompareOptions>, Box<dyn std::error::Error>> {
+        let baseline = args.opt_value_from_str("--baseline")?;
+        let threshold = args.opt_value_from_str("--threshold")?;
+
+        if !args.contains("--compare") {
+            return Ok(None);
+        }
+
+        Ok(Some(all::CompareOptions {
+            baseline,
+            threshold: threshold.unwrap_or(bench_history::DEFAULT_THRESHOLD),
+        }))
+    }
+
+    /// Reads the day range and the `--skip`, `--only-unsolved` and `--only-failing` options.
+    fn parse_filter(
+        args: &mut pico_args::Arguments,
+    ) -> Result<all::DayFilter, Box<dyn std::error::Error>> {
+        let skip = args.values_from_str("--skip")?;
+        let only_unsolved = args.contains("--only-unsolved");
+        let only_failing = args.contains("--only-failing");
+
+        Ok(all::DayFilter {
+            range: args.opt_free_from_str()?.unwrap_or_else(DayRange::all),
+            skip,
+            only_unsolved,
+            only_failing,
+        })
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
-            },
+            Some("all") => AppArguments::All(all::AllOptions {
+                year: parse_year(&mut args)?,
+                is_release: args.contains("--release"),
+                is_timed: args.contains("--time"),
+                budget: args.opt_value_from_str("--budget")?,
+                timeout: args.opt_value_from_str("--timeout")?,
+                jobs: args.opt_value_from_str("--jobs")?,
+                compare: parse_compare(&mut args)?,
+                save_baseline: args.opt_value_from_str("--save-baseline")?,
+                filter: parse_filter(&mut args)?,
+            }),
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
+                wait: args.contains("--wait"),
                 time: args.contains("--time"),
+                budget: args.opt_value_from_str("--budget")?,
+                timeout: args.opt_value_from_str("--timeout")?,
+            },
+            Some("verify") => AppArguments::Verify {
+                year: parse_year(&mut args)?,
+                release: args.contains("--release"),
+                day: args.opt_free_from_str()?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -77,16 +163,22 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All(options) => all::handle(options, get_registry()),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold { puzzle } => scaffold::handle(puzzle),
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 time,
+                budget,
+                timeout,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                wait,
+            } => solve::handle(puzzle, release, time, budget, timeout, submit, wait),
+            AppArguments::Verify { year, day, release } => {
+                verify::handle(year, day, release, get_registry());
+            }
         },
     };
 }
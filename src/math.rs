@@ -0,0 +1,225 @@
+use num_traits::{PrimInt, Signed};
+
+/// Returns the greatest common divisor, which is never negative. `gcd(0, 0)` is `0`.
+///
+/// Cannot overflow, except for `gcd(T::min_value(), 0)` of signed types whose result is not representable.
+pub fn gcd<T: PrimInt>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+
+    while !b.is_zero() {
+        (a, b) = (b, a % b);
+    }
+
+    if a < T::zero() {
+        T::zero() - a
+    } else {
+        a
+    }
+}
+
+/// Returns the least common multiple, which is never negative,
+/// or [`None`] if it does not fit into `T`.
+///
+/// ```
+/// # use advent_of_code::math::lcm;
+/// assert_eq!(lcm(4_u64, 6), Some(12));
+/// assert_eq!(lcm(u64::MAX, 2), None);
+/// ```
+pub fn lcm<T: PrimInt>(a: T, b: T) -> Option<T> {
+    if a.is_zero() || b.is_zero() {
+        return Some(T::zero());
+    }
+
+    // divide first, so only the result itself can overflow.
+    let result = (a / gcd(a, b)).checked_mul(&b)?;
+
+    if result < T::zero() {
+        T::zero().checked_sub(&result)
+    } else {
+        Some(result)
+    }
+}
+
+/// Extended Euclidean algorithm.
+/// Returns `(g, x, y)` where `g` is the non-negative [`gcd`] of `a` and `b`, and `a * x + b * y = g`.
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while !r.is_zero() {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < T::zero() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Returns the remainder of `a / modulus` in `0..modulus`, also for negative `a`.
+/// `modulus` must be positive.
+pub fn modulo<T: PrimInt>(a: T, modulus: T) -> T {
+    let remainder = a % modulus;
+
+    if remainder < T::zero() {
+        remainder + modulus
+    } else {
+        remainder
+    }
+}
+
+/// Returns `x` in `0..modulus` such that `a * x ≡ 1 (mod modulus)`,
+/// or [`None`] if `a` and `modulus` are not coprime.
+pub fn mod_inverse<T: PrimInt + Signed>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(modulo(a, modulus), modulus);
+    g.is_one().then(|| modulo(x, modulus))
+}
+
+/// Returns `base.pow(exponent) % modulus` without overflowing, even if `modulus` is close to `T::max_value()`.
+/// `exponent` must not be negative and `modulus` must be positive.
+///
+/// ```
+/// # use advent_of_code::math::mod_pow;
+/// assert_eq!(mod_pow(4_u32, 13, 497), 445);
+/// assert_eq!(mod_pow(2_u64, 64, u64::MAX), 1);
+/// ```
+pub fn mod_pow<T: PrimInt>(base: T, exponent: T, modulus: T) -> T {
+    let mut result = modulo(T::one(), modulus);
+    let mut base = modulo(base, modulus);
+    let mut exponent = exponent;
+
+    while exponent > T::zero() {
+        if (exponent & T::one()).is_one() {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent = exponent >> 1;
+    }
+
+    result
+}
+
+/// Solves a system of congruences `x ≡ remainder (mod modulus)` with the Chinese Remainder Theorem.
+/// The moduli need not be coprime.
+///
+/// Returns `(x, m)` such that the solutions are exactly `x + k * m`, with `x` in `0..m`.
+/// Returns [`None`] if the congruences contradict each other, or if `m` does not fit into `T`.
+/// All moduli must be positive.
+///
+/// ```
+/// # use advent_of_code::math::crt;
+/// assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// // 4 and 6 are not coprime, the solutions repeat every lcm(4, 6) = 12 steps.
+/// assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+/// assert_eq!(crt([(1, 4), (2, 6)]), None);
+/// ```
+pub fn crt<T: PrimInt + Signed>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::zero(), T::one()), |(r1, m1), (r2, m2)| {
+            let r2 = modulo(r2, m2);
+            let g = gcd(m1, m2);
+            let difference = r2 - r1;
+
+            if !(difference % g).is_zero() {
+                return None;
+            }
+
+            // solve r1 + m1 * k ≡ r2 (mod m2) for k.
+            let m2 = m2 / g;
+            let inverse = mod_inverse(m1 / g, m2)?;
+            let k = mod_mul(modulo(difference / g, m2), inverse, m2);
+
+            // k < m2, so neither of these can overflow if the combined modulus fits.
+            let modulus = m1.checked_mul(&m2)?;
+            Some((r1 + m1 * k, modulus))
+        })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns `a * b % modulus` for `a` and `b` in `0..modulus`, falling back to double-and-add if the product overflows.
+fn mod_mul<T: PrimInt>(a: T, b: T, modulus: T) -> T {
+    if let Some(product) = a.checked_mul(&b) {
+        return product % modulus;
+    }
+
+    let (mut a, mut b) = (a, b);
+    let mut result = T::zero();
+
+    while b > T::zero() {
+        if (b & T::one()).is_one() {
+            result = mod_add(result, a, modulus);
+        }
+        a = mod_add(a, a, modulus);
+        b = b >> 1;
+    }
+
+    result
+}
+
+/// Returns `(a + b) % modulus` for `a` and `b` in `0..modulus` without overflowing.
+fn mod_add<T: PrimInt>(a: T, b: T, modulus: T) -> T {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{crt, extended_gcd, gcd, lcm, mod_inverse, mod_pow, modulo};
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(48_u32, 18), 6);
+        assert_eq!(gcd(18_u32, 48), 6);
+        assert_eq!(gcd(-48_i32, 18), 6);
+        assert_eq!(gcd(0_u32, 7), 7);
+        assert_eq!(gcd(0_u32, 0), 0);
+
+        assert_eq!(lcm(4_u8, 6), Some(12));
+        assert_eq!(lcm(-4_i32, 6), Some(12));
+        assert_eq!(lcm(0_u8, 6), Some(0));
+        // the product of the two overflows, the lcm does not.
+        assert_eq!(lcm(200_u8, 100), Some(200));
+        assert_eq!(lcm(200_u8, 3), None);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        let (g, x, y) = extended_gcd(240_i64, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(modulo(-7_i32, 3), 2);
+        assert_eq!(mod_inverse(3_i32, 11), Some(4));
+        assert_eq!(mod_inverse(-3_i32, 11), Some(7));
+        assert_eq!(mod_inverse(2_i32, 4), None);
+
+        assert_eq!(mod_pow(4_u32, 13, 497), 445);
+        assert_eq!(mod_pow(-2_i32, 3, 5), 2);
+        assert_eq!(mod_pow(7_u8, 0, 1), 0);
+        assert_eq!(mod_pow(3_u64, u64::MAX - 59, u64::MAX - 58), 1);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt([(2_i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(2_i64, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(-1_i64, 4), (3, 8)]), Some((3, 8)));
+        assert_eq!(crt([(1_i64, 4), (2, 6)]), None);
+        assert_eq!(crt(std::iter::empty::<(i64, i64)>()), Some((0, 1)));
+
+        // the moduli are coprime, but their product does not fit.
+        assert_eq!(crt([(1_i8, 11), (2, 13)]), None);
+    }
+}
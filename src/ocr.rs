@@ -0,0 +1,355 @@
+use crate::grid::Grid;
+use crate::point::Point;
+
+/// A font of block letters: the height of a glyph and the glyphs themselves.
+struct Font {
+    height: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+/// The small font, e.g. of 2016 day 8, 2019 days 8 and 11, 2021 day 13 and 2022 day 10.
+const FONT_4X6: Font = Font {
+    height: 6,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+/// The large font, e.g. of 2018 day 10.
+const FONT_6X10: Font = Font {
+    height: 10,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+/// Whether a text has the shape of a drawing: several lines of only `#`, `.` and spaces, with at least one `#`.
+/// Only drawings are worth passing to [`recognize`].
+#[must_use]
+pub fn is_drawing(text: &str) -> bool {
+    let text = text.trim_matches(|c| c == '\n' || c == '\r');
+
+    text.contains('\n')
+        && text.contains('#')
+        && text
+            .chars()
+            .all(|c| matches!(c, '#' | '.' | ' ' | '\n' | '\r'))
+}
+
+/// Reads the text spelled by block letters drawn with `#` and `.` (or spaces), as printed by some puzzles.
+/// Both the 4x6 and the 6x10 font are recognized, the font is picked by the height of the letters.
+/// Letters are separated by blank columns. Blank rows and columns around the letters and lines of different lengths are tolerated.
+///
+/// Returns [`None`] if the image is not made of block letters or contains a letter that is not known.
+///
+/// ```
+/// # use advent_of_code::ocr::recognize;
+/// let image = "\
+/// #..#.###.
+/// #..#..#..
+/// ####..#..
+/// #..#..#..
+/// #..#..#..
+/// #..#.###.";
+/// assert_eq!(recognize(image), Some("HI".into()));
+/// assert_eq!(recognize("42"), None);
+/// ```
+pub fn recognize(image: &str) -> Option<String> {
+    let lines: Vec<&str> = image
+        .trim_matches(|c| c == '\n' || c == '\r')
+        .lines()
+        .collect();
+    let width = lines.iter().map(|line| line.chars().count()).max()?;
+
+    let mut cells = Vec::with_capacity(width * lines.len());
+
+    for line in &lines {
+        for c in line.chars() {
+            cells.push(match c {
+                '#' => true,
+                '.' | ' ' => false,
+                _ => return None,
+            });
+        }
+        cells.extend((line.chars().count()..width).map(|_| false));
+    }
+
+    recognize_grid(&Grid::new(width, lines.len(), cells)?)
+}
+
+/// Reads the text spelled by block letters in a grid of lit cells, see [`recognize`].
+pub fn recognize_grid(grid: &Grid<bool>) -> Option<String> {
+    let is_lit = |x: usize, y: usize| grid[Point::new(x, y)];
+
+    // every letter has lit cells in its top and bottom row, so blank rows are not part of the text.
+    let mut lit_rows = (0..grid.height()).filter(|&y| (0..grid.width()).any(|x| is_lit(x, y)));
+    let top = lit_rows.next()?;
+    let bottom = lit_rows.next_back().unwrap_or(top);
+
+    let font = [FONT_4X6, FONT_6X10]
+        .into_iter()
+        .find(|font| font.height == bottom - top + 1)?;
+
+    let columns: Vec<Column> = (0..grid.width())
+        .map(|x| {
+            (top..=bottom)
+                .filter(|&y| is_lit(x, y))
+                .fold(0, |column, y| column | 1 << (y - top))
+        })
+        .collect();
+
+    let mut glyphs: Vec<(char, Vec<Column>)> = font
+        .glyphs
+        .iter()
+        .map(|&(letter, rows)| (letter, get_columns(rows)))
+        .collect();
+    // prefer the widest glyph when letters touch, see [`read_letters`].
+    glyphs.sort_by_key(|(_, glyph)| std::cmp::Reverse(glyph.len()));
+
+    let text: String = columns
+        .split(|&column| column == 0)
+        .filter(|run| !run.is_empty())
+        .map(|run| read_letters(run, &glyphs))
+        .collect::<Option<_>>()?;
+
+    (!text.is_empty()).then_some(text)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The lit cells of a column of a glyph, bit `y` is set if row `y` is lit.
+type Column = u16;
+
+/// Reads a run of columns that are not blank.
+/// That is usually a single letter, but wide letters like `Y` can touch the next one.
+fn read_letters(run: &[Column], glyphs: &[(char, Vec<Column>)]) -> Option<String> {
+    if run.is_empty() {
+        return Some(String::new());
+    }
+
+    glyphs
+        .iter()
+        .filter(|(_, glyph)| run.starts_with(glyph))
+        .find_map(|(letter, glyph)| {
+            let rest = read_letters(&run[glyph.len()..], glyphs)?;
+            Some(format!("{letter}{rest}"))
+        })
+}
+
+/// Converts the rows of a glyph of a [`Font`], without blank columns on either side.
+/// Glyphs narrower or wider than the others, like `I` and `Y`, are then compared by their shape alone.
+fn get_columns(rows: &[&str]) -> Vec<Column> {
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let columns: Vec<Column> = (0..width)
+        .map(|x| {
+            rows.iter()
+                .enumerate()
+                .filter(|(_, row)| row.as_bytes().get(x) == Some(&b'#'))
+                .fold(0, |column, (y, _)| column | 1 << y)
+        })
+        .collect();
+
+    let start = columns.iter().position(|&column| column != 0).unwrap_or(0);
+    let end = columns
+        .iter()
+        .rposition(|&column| column != 0)
+        .map_or(0, |x| x + 1);
+    columns[start..end.max(start)].to_vec()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{is_drawing, recognize, recognize_grid};
+    use crate::grid::Grid;
+
+    #[test]
+    fn reads_small_letters() {
+        // 2022 day 10, with a trailing empty column.
+        let image = "\
+###..####.####.#..#.####.####.#..#..##..
+#..#....#.#....#.#..#.......#.#..#.#..#.
+#..#...#..###..##...###....#..####.#..#.
+###...#...#....#.#..#.....#...#..#.####.
+#.#..#....#....#.#..#....#....#..#.#..#.
+#..#.####.####.#..#.####.####.#..#.#..#.";
+        assert_eq!(recognize(image), Some("RZEKEZHA".into()));
+
+        // narrow and wide letters, with lines that lack their trailing empty columns.
+        let image = "\n.###.#...#\n..#..#...#\n..#...#.#.\n..#....#\n..#....#\n.###...#\n";
+        assert_eq!(recognize(image), Some("IY".into()));
+
+        // shifted by a leading blank column and a blank row, with a wide letter that touches the next one.
+        let image = "\
+..........
+.#...#.##.
+.#...##..#
+..#.#.#..#
+...#..####
+...#..#..#
+...#..#..#";
+        assert_eq!(recognize(image), Some("YA".into()));
+    }
+
+    #[test]
+    fn reads_large_letters() {
+        let image = "\
+#....#..######
+#....#..#.....
+.#..#...#.....
+.#..#...#.....
+..##....#####.
+..##....#.....
+.#..#...#.....
+.#..#...#.....
+#....#..#.....
+#....#..#.....";
+        assert_eq!(recognize(image), Some("XF".into()));
+
+        let grid = Grid::parse_with(image, |c| Some(c == '#')).unwrap();
+        assert_eq!(recognize_grid(&grid), Some("XF".into()));
+    }
+
+    #[test]
+    fn rejects_other_images() {
+        assert_eq!(recognize("12345"), None);
+        assert_eq!(recognize(""), None);
+        assert_eq!(recognize(".#.\n#.#\n.#."), None);
+        // an unknown letter in between known ones.
+        let image = "#..#.#...\n#..#.#...\n####.##..\n#..#.#.#.\n#..#.#..#\n#..#.#...";
+        assert_eq!(recognize(image), None);
+        assert_eq!(recognize("x\nx\nx\nx\nx\nx"), None);
+    }
+
+    #[test]
+    fn detects_drawings() {
+        assert!(is_drawing("\n.##.\n#..#\n"));
+        assert!(is_drawing("# #\n ##"));
+        assert!(!is_drawing("#..#"));
+        assert!(!is_drawing("...\n..."));
+        assert!(!is_drawing("12\n34"));
+    }
+}
@@ -0,0 +1,467 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Splits the puzzle input into lines.
+pub fn lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    Span::new(input).lines()
+}
+
+/// Splits the puzzle input into sections that are separated by blank lines.
+pub fn sections(input: &str) -> impl Iterator<Item = Span<'_>> {
+    Span::new(input).sections()
+}
+
+/// Extracts all integers from the puzzle input, a `-` directly in front of a number makes it negative.
+pub fn signed<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T::Err: Display,
+{
+    Span::new(input).signed()
+}
+
+/// Extracts all integers from the puzzle input, ignoring any signs.
+pub fn unsigned<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T::Err: Display,
+{
+    Span::new(input).unsigned()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A piece of the puzzle input that remembers where it came from, so errors can point to their line and column.
+///
+/// ```
+/// # use advent_of_code::parse::{self, Span};
+/// let input = "Game 1: 3 blue, 4 red\nGame 2: 1 green";
+///
+/// for line in parse::lines(input) {
+///     let (id, cubes) = line.pattern::<(u32, Span)>("Game {}: {}").unwrap();
+///     let counts = cubes.unsigned::<u32>().unwrap();
+/// }
+///
+/// let error = parse::lines(input).nth(1).unwrap().pattern::<(u32, u32)>("Game {}: {} blue");
+/// assert_eq!(error.unwrap_err().to_string(), "line 2, column 9: expected ` blue` at the end");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    source: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// Creates a [`Span`] that covers all of `source`.
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            text: source,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Returns the line and column where the span starts, both counting from 1.
+    pub fn position(&self) -> (usize, usize) {
+        let before = &self.source[..self.text.as_ptr() as usize - self.source.as_ptr() as usize];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    /// Creates an error that points to the span, or to its first line if it spans several.
+    pub fn error(&self, message: impl Display) -> ParseError {
+        let (line, column) = self.position();
+        let start = self.text.as_ptr() as usize - self.source.as_ptr() as usize;
+        let line_start = self.source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let excerpt = self.source[line_start..].lines().next().unwrap_or_default();
+
+        ParseError {
+            line,
+            column,
+            length: self.text.lines().next().unwrap_or_default().chars().count(),
+            excerpt: excerpt.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        self.text.lines().map(move |line| span.slice(line))
+    }
+
+    /// Splits into sections that are separated by one or more blank lines.
+    pub fn sections(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        let mut lines = self.text.lines().peekable();
+
+        std::iter::from_fn(move || {
+            while lines.next_if(|line| line.trim().is_empty()).is_some() {}
+
+            let first = lines.next()?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+                last = line;
+            }
+
+            let start = span.offset(first);
+            let end = span.offset(last) + last.len();
+            Some(span.slice(&span.text[start..end]))
+        })
+    }
+
+    /// Splits into spans of single characters.
+    pub fn chars(&self) -> impl Iterator<Item = (char, Span<'a>)> {
+        let span = *self;
+        self.text
+            .char_indices()
+            .map(move |(i, c)| (c, span.slice(&span.text[i..i + c.len_utf8()])))
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.slice(self.text.trim())
+    }
+
+    pub fn split<'b>(&self, separator: &'b str) -> impl Iterator<Item = Span<'a>> + 'b
+    where
+        'a: 'b,
+    {
+        let span = *self;
+        self.text.split(separator).map(move |part| span.slice(part))
+    }
+
+    pub fn split_whitespace(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        self.text
+            .split_whitespace()
+            .map(move |part| span.slice(part))
+    }
+
+    /// Splits at the first occurrence of `separator`, or fails if there is none.
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let (left, right) = self
+            .text
+            .split_once(separator)
+            .ok_or_else(|| self.error(format!("expected `{separator}`")))?;
+        Ok((self.slice(left), self.slice(right)))
+    }
+
+    /// Splits a `key: value` line.
+    pub fn key_value(&self) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let (key, value) = self
+            .text
+            .split_once(':')
+            .ok_or_else(|| self.error("expected `key: value`"))?;
+        Ok((self.slice(key.trim()), self.slice(value.trim())))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .map(|rest| self.slice(rest))
+            .ok_or_else(|| self.error(format!("expected `{prefix}`")))
+    }
+
+    /// Parses the span with [`FromStr`], surrounding whitespace is ignored.
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError>
+    where
+        T::Err: Display,
+    {
+        let text = self.trim();
+        text.text
+            .parse()
+            .map_err(|e| text.error(format!("cannot parse `{}`: {e}", text.text)))
+    }
+
+    /// Extracts all integers, a `-` directly in front of a number makes it negative.
+    /// Use [`Span::unsigned`] where `-` separates numbers, like in `1-3`.
+    pub fn signed<T: FromStr>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        self.integers(true)
+    }
+
+    /// Extracts all integers, ignoring any signs.
+    pub fn unsigned<T: FromStr>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        self.integers(false)
+    }
+
+    /// Matches the span against a `pattern` in which every `{}` captures text up to the literal text that follows it.
+    /// A `{}` at the end captures the rest of the span.
+    ///
+    /// # Panics
+    ///
+    /// If two `{}` are not separated by text, as it would be ambiguous where the first one ends.
+    pub fn captures(&self, pattern: &str) -> Result<Vec<Span<'a>>, ParseError> {
+        let mut literals = pattern.split("{}");
+        let first = literals.next().unwrap_or_default();
+        let literals: Vec<&str> = literals.collect();
+
+        let mut rest = self.strip_prefix(first)?;
+        let mut captures = Vec::with_capacity(literals.len());
+
+        for (i, literal) in literals.iter().enumerate() {
+            let end = if i + 1 == literals.len() {
+                rest.text
+                    .strip_suffix(literal)
+                    .ok_or_else(|| rest.error(format!("expected `{literal}` at the end")))?
+                    .len()
+            } else {
+                assert!(!literal.is_empty(), "`{{}}` must be separated by text");
+                rest.text
+                    .find(literal)
+                    .ok_or_else(|| rest.error(format!("expected `{literal}`")))?
+            };
+
+            captures.push(rest.slice(&rest.text[..end]));
+            rest = rest.slice(&rest.text[end + literal.len()..]);
+        }
+
+        if !rest.is_empty() {
+            return Err(rest.error(format!("unexpected `{}`", rest.text)));
+        }
+
+        Ok(captures)
+    }
+
+    /// Matches the span against a `pattern` like [`Span::captures`] and converts the captures into a tuple.
+    ///
+    /// # Panics
+    ///
+    /// If the number of `{}` in `pattern` does not match the size of the tuple.
+    pub fn pattern<T: FromCaptures<'a>>(&self, pattern: &str) -> Result<T, ParseError> {
+        T::from_captures(self.captures(pattern)?)
+    }
+
+    /// Returns a span for `text`, which must be a slice of this span.
+    fn slice(&self, text: &'a str) -> Span<'a> {
+        Span {
+            source: self.source,
+            text,
+        }
+    }
+
+    /// Returns the byte offset of `text`, which must be a slice of this span.
+    fn offset(&self, text: &str) -> usize {
+        text.as_ptr() as usize - self.text.as_ptr() as usize
+    }
+
+    fn integers<T: FromStr>(&self, signed: bool) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut numbers = vec![];
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+
+            let start = if signed && i > 0 && bytes[i - 1] == b'-' {
+                i - 1
+            } else {
+                i
+            };
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+
+            numbers.push(self.slice(&self.text[start..i]).parse()?);
+        }
+
+        Ok(numbers)
+    }
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A value that can be converted from a single capture of [`Span::pattern`].
+/// Numbers are parsed with surrounding whitespace ignored, use a [`Span`] to parse other types yourself.
+pub trait FromCapture<'a>: Sized {
+    fn from_capture(capture: Span<'a>) -> Result<Self, ParseError>;
+}
+
+impl<'a> FromCapture<'a> for Span<'a> {
+    fn from_capture(capture: Span<'a>) -> Result<Self, ParseError> {
+        Ok(capture)
+    }
+}
+
+impl<'a> FromCapture<'a> for &'a str {
+    fn from_capture(capture: Span<'a>) -> Result<Self, ParseError> {
+        Ok(capture.as_str())
+    }
+}
+
+impl FromCapture<'_> for String {
+    fn from_capture(capture: Span<'_>) -> Result<Self, ParseError> {
+        Ok(capture.as_str().to_string())
+    }
+}
+
+impl FromCapture<'_> for char {
+    fn from_capture(capture: Span<'_>) -> Result<Self, ParseError> {
+        let mut chars = capture.as_str().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(capture.error(format!(
+                "cannot parse `{capture}`: expected a single character"
+            ))),
+        }
+    }
+}
+
+macro_rules! impl_from_capture {
+    ($($t:ty),+) => {
+        $(
+            impl FromCapture<'_> for $t {
+                fn from_capture(capture: Span<'_>) -> Result<Self, ParseError> {
+                    capture.parse()
+                }
+            }
+        )+
+    };
+}
+
+impl_from_capture!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A tuple that can be converted from the captures of [`Span::pattern`].
+pub trait FromCaptures<'a>: Sized {
+    fn from_captures(captures: Vec<Span<'a>>) -> Result<Self, ParseError>;
+}
+
+macro_rules! impl_from_captures {
+    ($($t:ident),+) => {
+        impl<'a, $($t: FromCapture<'a>),+> FromCaptures<'a> for ($($t,)+) {
+            #[allow(non_snake_case)]
+            fn from_captures(captures: Vec<Span<'a>>) -> Result<Self, ParseError> {
+                let [$($t),+] = captures[..] else {
+                    panic!("pattern has {} captures, expected {}", captures.len(), [$(stringify!($t)),+].len());
+                };
+                Ok(($($t::from_capture($t)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(A);
+impl_from_captures!(A, B);
+impl_from_captures!(A, B, C);
+impl_from_captures!(A, B, C, D);
+impl_from_captures!(A, B, C, D, E);
+impl_from_captures!(A, B, C, D, E, F);
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when parsing the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the input, counting from 1.
+    pub line: usize,
+    /// The column in the line, counting from 1.
+    pub column: usize,
+    /// The number of characters the error points to, `0` at the end of a line.
+    pub length: usize,
+    /// The line of the input the error points to.
+    pub excerpt: String,
+    pub message: String,
+}
+
+impl Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{lines, sections, signed, unsigned, Span};
+
+    #[test]
+    fn extracts_integers() {
+        let input = "x=-5, y=12..-3\nrange 1-3";
+        assert_eq!(signed::<i32>(input).unwrap(), vec![-5, 12, -3, 1, -3]);
+        assert_eq!(unsigned::<u32>(input).unwrap(), vec![5, 12, 3, 1, 3]);
+
+        let error = unsigned::<u8>("1 2\n3 256").unwrap_err();
+        assert_eq!((error.line, error.column, error.length), (2, 3, 3));
+        assert_eq!(error.excerpt, "3 256");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: cannot parse `256`: number too large to fit in target type"
+        );
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "seeds: 1 2\n\nfirst:\n1 2 3\n4 5 6\n\n\nsecond:\n7 8 9\n";
+        let sections: Vec<Span> = sections(input).collect();
+
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[1].as_str(), "first:\n1 2 3\n4 5 6");
+        assert_eq!(sections[2].position(), (8, 1));
+
+        let (key, value) = sections[0].key_value().unwrap();
+        assert_eq!((key.as_str(), value.as_str()), ("seeds", "1 2"));
+        assert_eq!(sections[2].lines().nth(1).unwrap().position(), (9, 1));
+    }
+
+    #[test]
+    fn matches_patterns() {
+        let line = lines("Card   1: 41 48 | 83 86").next().unwrap();
+
+        let (id, winning, drawn) = line
+            .pattern::<(u32, Span, &str)>("Card {}: {} | {}")
+            .unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(winning.as_str(), "41 48");
+        assert_eq!(winning.position(), (1, 11));
+        assert_eq!(drawn, "83 86");
+
+        let (direction, steps) = Span::new("R 12").pattern::<(char, usize)>("{} {}").unwrap();
+        assert_eq!((direction, steps), ('R', 12));
+
+        let error = line.pattern::<(u32, u32)>("Card {}: {} |").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 11: expected ` |` at the end"
+        );
+
+        let error = line.pattern::<(u32,)>("Game {}").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: expected `Game `");
+
+        let error = Span::new("a -> b")
+            .pattern::<(char, char)>("{} => {}")
+            .unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: expected ` => `");
+    }
+}
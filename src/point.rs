@@ -0,0 +1,324 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num_traits::{CheckedAdd, CheckedSub, One, Signed, Zero};
+
+/// A point in 2D space. `y` grows downwards, like the rows of a puzzle input.
+///
+/// ```
+/// # use advent_of_code::point::{Direction, Point};
+/// let point = Point::new(2, 3) + Direction::North;
+/// assert_eq!(point, Point::new(2, 2));
+/// assert_eq!(point.manhattan(Point::new(0, 0)), 4);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + PartialOrd + Sub<Output = T> + Add<Output = T>> Point<T> {
+    /// Returns the manhattan distance to another point.
+    /// Works for unsigned coordinates as well, as the difference is always taken from the larger value.
+    pub fn manhattan(self, other: Self) -> T {
+        let distance = |a: T, b: T| if a > b { a - b } else { b - a };
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+}
+
+impl<T: Copy + Zero + One + CheckedAdd + CheckedSub> Point<T> {
+    /// Returns the point one step in `direction`, or [`None`] if that would overflow,
+    /// e.g. when stepping north from `y = 0` with unsigned coordinates.
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        let offset = |delta: i8, value: T| match delta {
+            1 => value.checked_add(&T::one()),
+            -1 => value.checked_sub(&T::one()),
+            _ => Some(value),
+        };
+        let (dx, dy) = direction.delta();
+        Some(Self::new(offset(dx, self.x)?, offset(dy, self.y)?))
+    }
+}
+
+impl<T: Copy + Signed> Point<T> {
+    /// Returns the orthogonal neighbours, clockwise starting from north.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::CARDINAL.into_iter().map(move |d| self + d)
+    }
+
+    /// Returns the orthogonal and diagonal neighbours, clockwise starting from north.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self + d)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+/// Scales both coordinates.
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Moves one step in a direction. Use [`Point::checked_step`] for unsigned coordinates.
+impl<T: Copy + Signed> Add<Direction> for Point<T> {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+}
+
+impl<T: Copy + Signed> AddAssign<Direction> for Point<T> {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A compass direction, on a map with north at the top.
+///
+/// ```
+/// # use advent_of_code::point::Direction;
+/// let direction = Direction::try_from('>').unwrap();
+/// assert_eq!(direction, Direction::East);
+/// assert_eq!(direction.turn_right(), Direction::South);
+/// assert_eq!(direction.opposite(), Direction::West);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise starting from north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise starting from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Returns the direction rotated by 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Returns the direction rotated by 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Returns the direction rotated by 180 degrees, same as [`Direction::opposite`].
+    pub fn turn_back(self) -> Self {
+        self.opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as u8 % 2 == 1
+    }
+
+    /// Returns the offset of one step in this direction.
+    pub fn offset<T: Signed>(self) -> Point<T> {
+        let (dx, dy) = self.delta();
+        let unit = |delta: i8| match delta {
+            1 => T::one(),
+            -1 => -T::one(),
+            _ => T::zero(),
+        };
+        Point::new(unit(dx), unit(dy))
+    }
+
+    fn delta(self) -> (i8, i8) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Rotates clockwise by `eighths` of a full turn.
+    fn rotate(self, eighths: u8) -> Self {
+        Self::ALL[(self as usize + eighths as usize) % 8]
+    }
+}
+
+/// Converts arrows (`^ > v <`), `U R D L` and `N E S W`.
+impl TryFrom<char> for Direction {
+    type Error = DirectionFromCharError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Direction::North),
+            '>' | 'R' | 'E' => Ok(Direction::East),
+            'v' | 'D' | 'S' => Ok(Direction::South),
+            '<' | 'L' | 'W' => Ok(Direction::West),
+            c => Err(DirectionFromCharError(c)),
+        }
+    }
+}
+
+/// An error which can be returned when converting a character into a [`Direction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectionFromCharError(pub char);
+
+impl Error for DirectionFromCharError {}
+
+impl Display for DirectionFromCharError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid direction `{}`, expecting one of `^>v<`, `URDL` or `NESW`",
+            self.0
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, Point};
+
+    #[test]
+    fn point_arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 4);
+
+        assert_eq!(a + b, Point::new(2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(a * 2, Point::new(6, -4));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(Point::new(1_usize, 5).manhattan(Point::new(4, 2)), 6);
+    }
+
+    #[test]
+    fn steps_in_directions() {
+        let mut point = Point::new(0_i64, 0);
+        point += Direction::South;
+        point += Direction::NorthWest;
+        assert_eq!(point, Point::new(-1, 0));
+        assert_eq!(point.neighbours4().count(), 4);
+        assert_eq!(point.neighbours8().count(), 8);
+
+        let point = Point::new(0_usize, 1);
+        assert_eq!(point.checked_step(Direction::North), Some(Point::new(0, 0)));
+        assert_eq!(point.checked_step(Direction::West), None);
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::NorthEast.turn_right(), Direction::SouthEast);
+        assert_eq!(Direction::South.turn_back(), Direction::North);
+        assert_eq!(Direction::SouthWest.opposite(), Direction::NorthEast);
+        assert!(Direction::NorthWest.is_diagonal());
+        assert!(!Direction::East.is_diagonal());
+    }
+
+    #[test]
+    fn parses_directions() {
+        let parse = |s: &str| {
+            s.chars()
+                .map(Direction::try_from)
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        assert_eq!(parse("^>v<").unwrap(), Direction::CARDINAL);
+        assert_eq!(parse("URDL").unwrap(), Direction::CARDINAL);
+        assert_eq!(parse("NESW").unwrap(), Direction::CARDINAL);
+        assert_eq!(
+            Direction::try_from('x').unwrap_err().to_string(),
+            "invalid direction `x`, expecting one of `^>v<`, `URDL` or `NESW`"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::parse::ParseError;
+use crate::{Day, Year};
+
+/// Identifies a single puzzle by its [`Year`] and [`Day`].
+///
+/// # Display
+/// This value displays as `{year}-{day}`, which is also the name of the solution binary.
+///
+/// ```
+/// # use advent_of_code::{day, year, PuzzleId};
+/// let puzzle = PuzzleId::new(year!(2023), day!(8));
+/// assert_eq!(puzzle.to_string(), "2023-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] for the given [`Year`] and [`Day`].
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error of a solution, with the location in the puzzle input if it is known.
+///
+/// # Display
+/// An error with a location displays like a compiler diagnostic, with a caret pointing at the bad input:
+///
+/// ```text
+/// error: unexpected character `X` for card
+///  --> 2023-07 parse, line 3, column 3
+///   |
+/// 3 | KKX77 28
+///   |   ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleError {
+    pub puzzle: PuzzleId,
+    /// `None` for the parse step of solutions implementing [`crate::template::Solution`].
+    pub part: Option<u8>,
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+/// A location in the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// The line of the input, counting from 1.
+    pub line: usize,
+    /// The column in the line, counting from 1.
+    pub column: usize,
+    /// The number of characters to point at.
+    pub length: usize,
+    /// The line of the input.
+    pub excerpt: String,
+}
+
+impl PuzzleError {
+    /// Creates a [`PuzzleError`] from the error of a solution step.
+    /// The location is taken from a [`ParseError`] if there is one in the chain of error sources.
+    pub fn new(puzzle: PuzzleId, part: Option<u8>, error: &(dyn Error + 'static)) -> Self {
+        let parse_error = std::iter::successors(Some(error), |&e| e.source())
+            .find_map(|e| e.downcast_ref::<ParseError>());
+
+        match parse_error {
+            Some(parse_error) => Self {
+                puzzle,
+                part,
+                message: parse_error.message.clone(),
+                location: Some(Location {
+                    line: parse_error.line,
+                    column: parse_error.column,
+                    length: parse_error.length,
+                    excerpt: parse_error.excerpt.clone(),
+                }),
+            },
+            None => Self {
+                puzzle,
+                part,
+                message: error.to_string(),
+                location: None,
+            },
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let step = match self.part {
+            Some(part) => format!("part {part}"),
+            None => "parse".into(),
+        };
+
+        writeln!(f, "error: {}", self.message)?;
+
+        let Some(location) = &self.location else {
+            return write!(f, " --> {} {step}", self.puzzle);
+        };
+
+        let gutter = " ".repeat(location.line.to_string().len());
+        let indent: String = location
+            .excerpt
+            .chars()
+            .take(location.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        writeln!(
+            f,
+            "{gutter}--> {} {step}, line {}, column {}",
+            self.puzzle, location.line, location.column
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", location.line, location.excerpt)?;
+        write!(
+            f,
+            "{gutter} | {indent}{}",
+            "^".repeat(location.length.max(1))
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PuzzleError, PuzzleId};
+    use crate::parse::Span;
+    use crate::template::SolutionError;
+    use crate::{day, year};
+
+    const PUZZLE: PuzzleId = PuzzleId::new(year!(2023), day!(7));
+
+    #[test]
+    fn renders_diagnostics() {
+        let input = "32T3K 765\nT55J5 684\nKKX77 28";
+        let error: SolutionError = Span::new(input)
+            .lines()
+            .nth(2)
+            .unwrap()
+            .pattern::<(u32, u32)>("{} {}")
+            .unwrap_err()
+            .into();
+
+        let error = PuzzleError::new(PUZZLE, Some(1), error.as_ref());
+        assert_eq!(
+            error.to_string(),
+            "error: cannot parse `KKX77`: invalid digit found in string\n --> 2023-07 part 1, line 3, column 1\n  |\n3 | KKX77 28\n  | ^^^^^"
+        );
+
+        let error: SolutionError = "no hands".into();
+        let error = PuzzleError::new(PUZZLE, None, error.as_ref());
+        assert_eq!(error.to_string(), "error: no hands\n --> 2023-07 parse");
+    }
+}
@@ -0,0 +1,372 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num_traits::Zero;
+
+use crate::grid::{Grid, Pos};
+
+/// The outcome of a search: the distance of every reached node and the node it was reached from.
+///
+/// ```
+/// # use advent_of_code::search::bfs;
+/// // walk a number line from 0 towards 3.
+/// let search = bfs(0, |&n: &i32| [n - 1, n + 1].into_iter().filter(|n| (0..=3).contains(n)));
+/// assert_eq!(search.distances[&3], 3);
+/// assert_eq!(search.path(&3), Some(vec![0, 1, 2, 3]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    pub distances: HashMap<N, C>,
+    /// The node every reached node was reached from. Starts have no predecessor.
+    pub predecessors: HashMap<N, N>,
+    /// The goal the search stopped at, if it was given one and reached it.
+    pub goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C> Search<N, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// Returns the distance to a node, or [`None`] if it was not reached.
+    pub fn distance(&self, node: &N) -> Option<&C> {
+        self.distances.get(node)
+    }
+
+    /// Returns the nodes from the start to `node`, or [`None`] if it was not reached.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last()?) {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from `start`, visiting every reachable node.
+/// Distances count the steps of the shortest path.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::from([start.clone()]);
+    search.distances.insert(start, 0);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = search.distances[&node];
+
+        for next in successors(&node) {
+            if search.distances.contains_key(&next) {
+                continue;
+            }
+            search.distances.insert(next.clone(), distance + 1);
+            search.predecessors.insert(next.clone(), node.clone());
+            queue.push_back(next);
+        }
+    }
+
+    search
+}
+
+/// Depth-first search from `start`, visiting every reachable node.
+/// Distances are the depth at which a node was first discovered, which is not necessarily the shortest path.
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut stack = vec![start.clone()];
+    search.distances.insert(start, 0);
+
+    while let Some(node) = stack.pop() {
+        let distance = search.distances[&node];
+
+        for next in successors(&node) {
+            if search.distances.contains_key(&next) {
+                continue;
+            }
+            search.distances.insert(next.clone(), distance + 1);
+            search.predecessors.insert(next.clone(), node.clone());
+            stack.push(next);
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm from `start`, where `successors` returns the neighbours of a node together with the cost of moving there.
+/// Stops as soon as a node satisfying `is_goal` is reached; pass `|_| false` to compute the distances of all reachable nodes.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::zero(), is_goal)
+}
+
+/// A* search from `start`, like [`dijkstra`] but guided by a `heuristic` that estimates the remaining cost to the goal.
+/// The heuristic must never overestimate, e.g. the manhattan distance on a grid, or the result might not be the shortest path.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    let mut heap = BinaryHeap::new();
+
+    search.distances.insert(start.clone(), C::zero());
+    heap.push(Entry {
+        priority: heuristic(&start),
+        cost: C::zero(),
+        node: start,
+    });
+
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        // skip entries that were superseded by a cheaper path.
+        if search.distances.get(&node).is_some_and(|&best| cost > best) {
+            continue;
+        }
+
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|&best| next_cost >= best)
+            {
+                continue;
+            }
+
+            search.distances.insert(next.clone(), next_cost);
+            search.predecessors.insert(next.clone(), node.clone());
+            heap.push(Entry {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    search
+}
+
+/// Returns all nodes reachable from any of `starts`, including the starts themselves.
+pub fn flood_fill<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited: HashSet<N> = HashSet::new();
+    let mut stack: Vec<N> = starts.into_iter().collect();
+    visited.extend(stack.iter().cloned());
+
+    while let Some(node) = stack.pop() {
+        for next in successors(&node) {
+            if visited.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+
+    visited
+}
+
+/// Returns the regions of orthogonally connected cells for which `is_open` holds that do not touch the border of the grid.
+///
+/// ```
+/// # use advent_of_code::{grid::Grid, point::Point, search::enclosed_regions};
+/// let grid = Grid::parse("#####\n#..#.\n#####").unwrap();
+/// let regions = enclosed_regions(&grid, |&c| c == '.');
+/// assert_eq!(regions.len(), 1);
+/// assert!(regions[0].contains(&Point::new(2, 1)));
+/// ```
+pub fn enclosed_regions<T>(grid: &Grid<T>, is_open: impl Fn(&T) -> bool) -> Vec<HashSet<Pos>> {
+    let is_border = |pos: &Pos| {
+        pos.x == 0 || pos.y == 0 || pos.x + 1 == grid.width() || pos.y + 1 == grid.height()
+    };
+
+    let mut seen: HashSet<Pos> = HashSet::new();
+    let mut regions = vec![];
+
+    for (pos, cell) in grid.iter() {
+        if !is_open(cell) || seen.contains(&pos) {
+            continue;
+        }
+
+        let region = flood_fill([pos], |&pos| {
+            grid.neighbours4(pos)
+                .filter(|&next| is_open(&grid[next]))
+                .collect::<Vec<_>>()
+        });
+
+        seen.extend(region.iter().copied());
+
+        if !region.iter().any(is_border) {
+            regions.push(region);
+        }
+    }
+
+    regions
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An entry of the priority queue, ordered so that [`BinaryHeap`] pops the lowest priority first.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, dfs, dijkstra, enclosed_regions, flood_fill};
+    use crate::grid::{Grid, Pos};
+    use crate::point::Point;
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    fn open_neighbours(grid: &Grid<char>, pos: Pos) -> Vec<Pos> {
+        grid.neighbours4(pos)
+            .filter(|&next| grid[next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let grid = Grid::parse(MAZE).unwrap();
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+
+        let search = bfs(start, |&pos| open_neighbours(&grid, pos));
+        assert_eq!(search.distance(&end), Some(&15));
+
+        let path = search.path(&end).unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+        assert_eq!(search.path(&Point::new(3, 0)), None);
+    }
+
+    #[test]
+    fn visits_all_nodes_depth_first() {
+        let grid = Grid::parse(MAZE).unwrap();
+        let start = grid.find(&'S').unwrap();
+
+        let depth_first = dfs(start, |&pos| open_neighbours(&grid, pos));
+        let breadth_first = bfs(start, |&pos| open_neighbours(&grid, pos));
+
+        assert_eq!(depth_first.distances.len(), breadth_first.distances.len());
+        assert!(depth_first
+            .distances
+            .iter()
+            .all(|(pos, &d)| d >= breadth_first.distances[pos]));
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        // moving onto a digit costs its value.
+        let grid = Grid::parse_with("1163\n1381\n2136", |c| c.to_digit(10)).unwrap();
+        let start = Point::new(0, 0);
+        let end = Point::new(3, 2);
+
+        let successors = |pos: &Pos| {
+            grid.neighbours4(*pos)
+                .map(|next| (next, grid[next]))
+                .collect::<Vec<_>>()
+        };
+
+        let search = dijkstra(start, successors, |&pos| pos == end);
+        assert_eq!(search.goal, Some(end));
+        assert_eq!(search.distance(&end), Some(&13));
+
+        let guided = astar(
+            start,
+            successors,
+            |pos| pos.manhattan(end) as u32,
+            |&pos| pos == end,
+        );
+        assert_eq!(guided.distance(&end), Some(&13));
+        assert_eq!(guided.path(&end).unwrap().first(), Some(&start));
+
+        let everything = dijkstra(start, successors, |_| false);
+        assert_eq!(everything.goal, None);
+        assert_eq!(everything.distances.len(), 12);
+    }
+
+    #[test]
+    fn fills_regions() {
+        let grid = Grid::parse("#####\n#..#.\n#.###\n#####\n##.##").unwrap();
+
+        let region = flood_fill([Point::new(1, 1)], |&pos| open_neighbours(&grid, pos));
+        assert_eq!(region.len(), 3);
+
+        // the cells at the right and the bottom edge are open to the outside.
+        let regions = enclosed_regions(&grid, |&c| c == '.');
+        assert_eq!(regions, vec![region]);
+    }
+}
@@ -0,0 +1,371 @@
+/// Module that keeps a local ledger of submitted answers in `data/<year>/answers.toml`.
+/// It remembers accepted answers, rejected guesses and the bounds hinted at by "too high" / "too low",
+/// so that guesses that are known to be wrong never reach the website.
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::submission::{Hint, SubmitOutcome};
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse answers: {e}"),
+            Error::IO(e) => write!(f, "could not access answers: {e}"),
+        }
+    }
+}
+
+/// Everything that is known about the answer of one part.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub incorrect: Vec<String>,
+    /// The lowest answer that was too high.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<String>,
+    /// The highest answer that was too low.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<String>,
+}
+
+impl PartAnswers {
+    fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    fn record_incorrect(&mut self, answer: &str, hint: Option<Hint>) {
+        if !self.incorrect.iter().any(|x| x == answer) {
+            self.incorrect.push(answer.to_string());
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return;
+        };
+
+        let (bound, is_tighter): (_, fn(i128, i128) -> bool) = match hint {
+            Some(Hint::TooHigh) => (&mut self.too_high, |a, b| a < b),
+            Some(Hint::TooLow) => (&mut self.too_low, |a, b| a > b),
+            None => return,
+        };
+
+        let current = bound.as_ref().and_then(|b| b.parse::<i128>().ok());
+        if current.is_none_or(|current| is_tighter(value, current)) {
+            *bound = Some(answer.to_string());
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(
+        rename = "part-1",
+        default,
+        skip_serializing_if = "PartAnswers::is_empty"
+    )]
+    part_1: PartAnswers,
+    #[serde(
+        rename = "part-2",
+        default,
+        skip_serializing_if = "PartAnswers::is_empty"
+    )]
+    part_2: PartAnswers,
+}
+
+/// Reasons for refusing to submit an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Blocked {
+    AlreadySolved { correct: String },
+    KnownIncorrect,
+    TooHigh { bound: String },
+    TooLow { bound: String },
+}
+
+impl Display for Blocked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Blocked::AlreadySolved { correct } => {
+                write!(f, "this part was already solved with `{correct}`.")
+            }
+            Blocked::KnownIncorrect => write!(f, "this answer was already rejected."),
+            Blocked::TooHigh { bound } => {
+                write!(
+                    f,
+                    "the answer is not lower than `{bound}`, which was too high."
+                )
+            }
+            Blocked::TooLow { bound } => {
+                write!(
+                    f,
+                    "the answer is not higher than `{bound}`, which was too low."
+                )
+            }
+        }
+    }
+}
+
+/// The answer ledger of one year, keyed by day.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[must_use]
+pub fn get_path(year: Year) -> String {
+    format!("data/{year}/answers.toml")
+}
+
+impl Answers {
+    /// Loads the ledger of a year. A missing file is treated as an empty ledger.
+    pub fn load(year: Year) -> Result<Self, Error> {
+        let path = get_path(year);
+        if !Path::new(&path).exists() {
+            return Ok(Self::default());
+        }
+        Self::from_toml(&fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, year: Year) -> Result<(), Error> {
+        let path = get_path(year);
+        if let Some(parent) = Path::new(&path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_toml()?)?;
+        Ok(())
+    }
+
+    fn from_toml(s: &str) -> Result<Self, Error> {
+        toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))
+    }
+
+    fn to_toml(&self) -> Result<String, Error> {
+        toml::to_string(self).map_err(|e| Error::Parser(e.to_string()))
+    }
+
+    /// Returns what is known about a part, if anything.
+    #[must_use]
+    pub fn get(&self, day: Day, part: u8) -> Option<&PartAnswers> {
+        let answers = self.days.get(&day.to_string())?;
+        let answers = if part == 1 {
+            &answers.part_1
+        } else {
+            &answers.part_2
+        };
+        (!answers.is_empty()).then_some(answers)
+    }
+
+    /// Whether correct answers are on record for both parts.
+    /// The second part of the last day is awarded without an answer, so only its first part counts.
+    #[must_use]
+    pub fn is_solved(&self, day: Day) -> bool {
+        let parts: &[u8] = if day == 25 { &[1] } else { &[1, 2] };
+        parts
+            .iter()
+            .all(|&part| self.get(day, part).is_some_and(|x| x.correct.is_some()))
+    }
+
+    fn get_mut(&mut self, day: Day, part: u8) -> &mut PartAnswers {
+        let answers = self.days.entry(day.to_string()).or_default();
+        if part == 1 {
+            &mut answers.part_1
+        } else {
+            &mut answers.part_2
+        }
+    }
+
+    /// Checks whether an answer is worth submitting.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), Blocked> {
+        let Some(known) = self.get(day, part) else {
+            return Ok(());
+        };
+
+        if let Some(correct) = &known.correct {
+            return Err(Blocked::AlreadySolved {
+                correct: correct.clone(),
+            });
+        }
+
+        if known.incorrect.iter().any(|x| x == answer) {
+            return Err(Blocked::KnownIncorrect);
+        }
+
+        // bounds only apply to numeric answers.
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        if let Some(bound) = &known.too_high {
+            if bound.parse::<i128>().is_ok_and(|bound| value >= bound) {
+                return Err(Blocked::TooHigh {
+                    bound: bound.clone(),
+                });
+            }
+        }
+
+        if let Some(bound) = &known.too_low {
+            if bound.parse::<i128>().is_ok_and(|bound| value <= bound) {
+                return Err(Blocked::TooLow {
+                    bound: bound.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the outcome of a submission.
+    /// Returns `false` if the outcome says nothing about the answer, e.g. when it was rate-limited.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, outcome: &SubmitOutcome) -> bool {
+        match outcome {
+            SubmitOutcome::Correct => {
+                self.get_mut(day, part).correct = Some(answer.to_string());
+            }
+            SubmitOutcome::Incorrect { hint } => {
+                self.get_mut(day, part).record_incorrect(answer, *hint);
+            }
+            SubmitOutcome::AlreadySolved {
+                answer: Some(correct),
+            } => {
+                self.get_mut(day, part).correct = Some(correct.clone());
+            }
+            SubmitOutcome::AlreadySolved { answer: None }
+            | SubmitOutcome::Cooldown { .. }
+            | SubmitOutcome::WrongLevel => return false,
+        }
+
+        true
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Blocked};
+    use crate::day;
+    use crate::template::submission::{Hint, SubmitOutcome};
+    use std::time::Duration;
+
+    fn incorrect(hint: Option<Hint>) -> SubmitOutcome {
+        SubmitOutcome::Incorrect { hint }
+    }
+
+    #[test]
+    fn blocks_known_incorrect_answers() {
+        let mut answers = Answers::default();
+        answers.record(day!(1), 1, "abc", &incorrect(None));
+
+        assert_eq!(
+            answers.check(day!(1), 1, "abc"),
+            Err(Blocked::KnownIncorrect)
+        );
+        assert_eq!(answers.check(day!(1), 1, "abd"), Ok(()));
+        assert_eq!(answers.check(day!(1), 2, "abc"), Ok(()));
+    }
+
+    #[test]
+    fn blocks_answers_outside_bounds() {
+        let mut answers = Answers::default();
+        answers.record(day!(3), 2, "100", &incorrect(Some(Hint::TooHigh)));
+        answers.record(day!(3), 2, "200", &incorrect(Some(Hint::TooHigh)));
+        answers.record(day!(3), 2, "10", &incorrect(Some(Hint::TooLow)));
+
+        assert_eq!(
+            answers.check(day!(3), 2, "150"),
+            Err(Blocked::TooHigh {
+                bound: "100".into()
+            })
+        );
+        assert_eq!(
+            answers.check(day!(3), 2, "5"),
+            Err(Blocked::TooLow { bound: "10".into() })
+        );
+        assert_eq!(answers.check(day!(3), 2, "50"), Ok(()));
+    }
+
+    #[test]
+    fn blocks_solved_parts() {
+        let mut answers = Answers::default();
+        answers.record(day!(2), 1, "8", &SubmitOutcome::Correct);
+
+        assert_eq!(
+            answers.check(day!(2), 1, "9"),
+            Err(Blocked::AlreadySolved {
+                correct: "8".into()
+            })
+        );
+    }
+
+    #[test]
+    fn tracks_solved_days() {
+        let mut answers = Answers::default();
+        answers.record(day!(2), 1, "8", &SubmitOutcome::Correct);
+        answers.record(day!(2), 2, "2287", &incorrect(None));
+        answers.record(day!(25), 1, "54", &SubmitOutcome::Correct);
+
+        assert!(!answers.is_solved(day!(2)));
+        assert!(answers.is_solved(day!(25)));
+
+        answers.record(day!(2), 2, "2286", &SubmitOutcome::Correct);
+        assert!(answers.is_solved(day!(2)));
+    }
+
+    #[test]
+    fn ignores_rate_limited_submissions() {
+        let mut answers = Answers::default();
+        let cooldown = SubmitOutcome::Cooldown {
+            remaining: Some(Duration::from_secs(30)),
+        };
+
+        assert!(!answers.record(day!(4), 1, "13", &cooldown));
+        assert_eq!(answers.get(day!(4), 1), None);
+
+        let solved = SubmitOutcome::AlreadySolved {
+            answer: Some("12".into()),
+        };
+        assert!(answers.record(day!(4), 1, "13", &solved));
+        assert_eq!(
+            answers.check(day!(4), 1, "13"),
+            Err(Blocked::AlreadySolved {
+                correct: "12".into()
+            })
+        );
+    }
+
+    #[test]
+    fn round_trips_toml() {
+        let mut answers = Answers::default();
+        answers.record(day!(1), 1, "142", &SubmitOutcome::Correct);
+        answers.record(day!(1), 2, "300", &incorrect(Some(Hint::TooLow)));
+
+        let toml = answers.to_toml().unwrap();
+        assert_eq!(
+            toml,
+            [
+                "[01.part-1]",
+                "correct = \"142\"",
+                "",
+                "[01.part-2]",
+                "incorrect = [\"300\"]",
+                "too_low = \"300\"",
+                "",
+            ]
+            .join("\n")
+        );
+        assert_eq!(Answers::from_toml(&toml).unwrap(), answers);
+    }
+}
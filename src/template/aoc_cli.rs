@@ -1,10 +1,12 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    fs,
     process::{Command, Output, Stdio},
 };
 
-use crate::Day;
+use crate::template::{get_data_path, get_input_path, get_puzzle_path};
+use crate::PuzzleId;
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -35,8 +37,9 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let puzzle_path = get_puzzle_path(puzzle);
+    create_data_dirs(puzzle)?;
 
     let args = build_args(
         "read",
@@ -45,15 +48,16 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
-        day,
+        puzzle,
     );
 
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+pub fn download(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+    create_data_dirs(puzzle)?;
 
     let args = build_args(
         "download",
@@ -64,7 +68,7 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
-        day,
+        puzzle,
     );
 
     let output = call_aoc_cli(&args)?;
@@ -74,38 +78,48 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+pub fn submit(puzzle: PuzzleId, part: u8, result: &str) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], puzzle);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
+    // capture the response so that the verdict can be recorded.
+    let output = Command::new("aoc")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
+/// aoc-cli does not create missing parent folders, so make sure the year's folders exist.
+fn create_data_dirs(puzzle: PuzzleId) -> Result<(), AocCommandError> {
+    for folder in ["inputs", "puzzles"] {
+        fs::create_dir_all(get_data_path(folder, puzzle)).map_err(|_| AocCommandError::IoError)?;
     }
+    Ok(())
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], puzzle: PuzzleId) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        puzzle.year.to_string(),
+        "--day".into(),
+        puzzle.day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
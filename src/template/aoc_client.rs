@@ -0,0 +1,292 @@
+/// Native client for the adventofcode.com website.
+/// Fetches inputs and puzzle pages and submits answers without relying on an external binary.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::markdown;
+use crate::PuzzleId;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The site asks automated tools to identify themselves via the user agent.
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/akozlev/aoc2023)"
+);
+
+const SESSION_FILE: &str = "adventofcode.session";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    BadStatus(u16),
+    Transport(String),
+    IoError,
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or create a `.adventofcode.session` file in your home directory."
+            ),
+            AocClientError::BadStatus(400) => {
+                write!(f, "adventofcode.com rejected the request, the session cookie might have expired.")
+            }
+            AocClientError::BadStatus(404) => {
+                write!(f, "adventofcode.com could not find the puzzle, it might not be unlocked yet.")
+            }
+            AocClientError::BadStatus(status) => {
+                write!(f, "adventofcode.com responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "could not reach adventofcode.com: {e}"),
+            AocClientError::IoError => write!(f, "could not write output files to file system."),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    /// Creates a client that talks to `base_url` (e.g. `https://adventofcode.com`) and authenticates with `session`.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    /// Creates a client from the environment.
+    /// The session is read from `AOC_SESSION` or a session file, the base url can be overridden via `AOC_BASE_URL`.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Fetches the personal puzzle input.
+    pub fn get_input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let url = format!("{}/input", self.get_puzzle_url(puzzle));
+        self.get(&url)
+    }
+
+    /// Fetches the html page of a puzzle. Once part one is solved, it includes the description of part two.
+    pub fn get_puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let url = self.get_puzzle_url(puzzle);
+        self.get(&url)
+    }
+
+    /// Submits an answer for one part of a puzzle and returns the html page of the response.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let url = format!("{}/answer", self.get_puzzle_url(puzzle));
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.get_cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+        response
+            .into_string()
+            .map_err(|e| AocClientError::Transport(e.to_string()))
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &self.get_cookie())
+            .call()?;
+        response
+            .into_string()
+            .map_err(|e| AocClientError::Transport(e.to_string()))
+    }
+
+    fn get_puzzle_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get_cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+/// Writes a fetched file, creating missing parent folders.
+pub fn write_file(path: &str, contents: &str) -> Result<(), AocClientError> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent).map_err(|_| AocClientError::IoError)?;
+    }
+    fs::write(path, contents).map_err(|_| AocClientError::IoError)
+}
+
+/// Extracts the `<article>` elements of a page. Puzzle pages contain one per unlocked part,
+/// answer pages contain a single one with the response message.
+pub fn get_articles(html: &str) -> Vec<&str> {
+    html.match_indices("<article")
+        .filter_map(|(start, _)| {
+            let len = html[start..].find("</article>")? + "</article>".len();
+            Some(&html[start..start + len])
+        })
+        .collect()
+}
+
+/// Converts all unlocked parts of a puzzle page to markdown.
+pub fn get_description(html: &str) -> String {
+    get_articles(html)
+        .iter()
+        .map(|article| markdown::from_html(article))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Looks up the session cookie, trying the `AOC_SESSION` variable before the session files used by aoc-cli.
+fn get_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)?;
+
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home.join(".config"));
+
+    [
+        home.join(format!(".{SESSION_FILE}")),
+        config_dir.join(SESSION_FILE),
+    ]
+    .iter()
+    .find_map(|path| fs::read_to_string(path).ok())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{get_articles, get_description, AocClient, AocClientError};
+    use crate::{day, year, PuzzleId};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    const PUZZLE: PuzzleId = PuzzleId::new(year!(2023), day!(8));
+
+    /// Serves a single canned response and hands back the raw request it received.
+    fn stub_server(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_input_with_session() {
+        let (base_url, server) = stub_server("200 OK", "1\n2\n3\n");
+        let client = AocClient::new(&base_url, "abc\n");
+
+        assert_eq!(client.get_input(PUZZLE).unwrap(), "1\n2\n3\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/8/input HTTP/1.1"));
+        assert!(request.contains("session=abc\r\n"));
+    }
+
+    #[test]
+    fn submits_answer_as_form() {
+        let (base_url, server) = stub_server("200 OK", "<main><article>ok</article></main>");
+        let client = AocClient::new(&base_url, "abc");
+
+        let html = client.submit(PUZZLE, 2, "6").unwrap();
+        assert_eq!(get_articles(&html), vec!["<article>ok</article>"]);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/8/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=6"));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (base_url, server) = stub_server("404 Not Found", "");
+        let client = AocClient::new(&base_url, "abc");
+
+        let result = client.get_puzzle(PUZZLE);
+        assert!(matches!(result, Err(AocClientError::BadStatus(404))));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn joins_unlocked_parts() {
+        let html = "<main><article><h2>--- Day 8 ---</h2></article><p>Your puzzle answer was <code>2</code>.</p><article><h2 id=\"part2\">--- Part Two ---</h2></article></main>";
+        assert_eq!(
+            get_description(html),
+            "## --- Day 8 ---\n\n## --- Part Two ---\n"
+        );
+    }
+}
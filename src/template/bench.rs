@@ -0,0 +1,201 @@
+/// Module that benchmarks solution parts and summarizes the distribution of their execution times.
+use std::{
+    cmp,
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// The time budget used if none is passed via `--budget`.
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+
+/// Benchmarks always collect at least this many samples, even if that exceeds the budget.
+const MIN_SAMPLES: usize = 10;
+
+/// Functions that are faster than this are timed in batches, so that the overhead of reading the clock does not dominate.
+const MIN_SAMPLE_TIME: Duration = Duration::from_micros(1);
+
+/// Benchmarks stop collecting samples at this many, even if the budget is not used up.
+/// Batches are sized so that the budget is usually spread over at most this many samples.
+const MAX_SAMPLES: usize = 10_000;
+
+/// Summary of the execution times of a benchmarked function.
+/// Durations are serialized as nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    /// The number of times the function ran. A sample is the mean of a batch of runs for fast functions.
+    #[serde(default)]
+    pub runs: usize,
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub mean: Duration,
+    #[serde(with = "nanos")]
+    pub p95: Duration,
+    #[serde(with = "nanos")]
+    pub stddev: Duration,
+    /// Samples outside of 1.5 times the interquartile range.
+    pub outliers: usize,
+}
+
+impl Stats {
+    /// Summarizes a set of samples. Returns [`None`] if there are none.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let nanos: Vec<f64> = sorted.iter().map(|x| x.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        let q1 = percentile(&sorted, 0.25).as_nanos() as f64;
+        let q3 = percentile(&sorted, 0.75).as_nanos() as f64;
+        let fence = 1.5 * (q3 - q1);
+        let outliers = nanos
+            .iter()
+            .filter(|&&x| x < q1 - fence || x > q3 + fence)
+            .count();
+
+        Some(Self {
+            samples: sorted.len(),
+            runs: sorted.len(),
+            min: sorted[0],
+            median: percentile(&sorted, 0.5),
+            mean: from_nanos(mean),
+            p95: percentile(&sorted, 0.95),
+            stddev: from_nanos(variance.sqrt()),
+            outliers,
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "└ {} samples: min {:.1?}, median {:.1?}, mean {:.1?}, p95 {:.1?}, stddev {:.1?}, outliers {}",
+            self.samples, self.min, self.median, self.mean, self.p95, self.stddev, self.outliers
+        )
+    }
+}
+
+/// Benchmarks a function for roughly `budget`.
+/// A warm-up phase of a tenth of the budget runs first and is used to estimate how many runs fit into a sample.
+/// At least [`MIN_SAMPLES`] and at most [`MAX_SAMPLES`] samples are collected.
+pub fn measure<I: Clone, T>(func: impl Fn(I) -> T, input: I, budget: Duration) -> Stats {
+    let timer = Instant::now();
+    let mut warmup_runs = 0_u32;
+
+    while warmup_runs == 0 || timer.elapsed() < budget / 10 {
+        black_box(func(input.clone()));
+        warmup_runs += 1;
+    }
+
+    let estimate = cmp::max(timer.elapsed().as_nanos() / u128::from(warmup_runs), 1);
+    let batch_time = cmp::max(MIN_SAMPLE_TIME, budget / MAX_SAMPLES as u32);
+    #[allow(clippy::cast_possible_truncation)]
+    let batch_size = cmp::max(batch_time.as_nanos() / estimate, 1) as u32;
+
+    let mut samples = vec![];
+    let timer = Instant::now();
+
+    while samples.len() < MIN_SAMPLES || (timer.elapsed() < budget && samples.len() < MAX_SAMPLES) {
+        // clone the inputs up front so that cloning is not part of the measurement.
+        let inputs: Vec<I> = (0..batch_size).map(|_| input.clone()).collect();
+
+        let sample_timer = Instant::now();
+        for input in inputs {
+            black_box(func(input));
+        }
+        samples.push(sample_timer.elapsed() / batch_size);
+    }
+
+    let mut stats = Stats::from_samples(&samples).expect("should have collected samples");
+    stats.runs = samples.len() * batch_size as usize;
+    stats
+}
+
+/// Returns the nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/// (De)serializes a [`Duration`] as a number of nanoseconds.
+pub(crate) mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        #[allow(clippy::cast_possible_truncation)]
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{measure, Stats};
+    use std::time::Duration;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_micros(x)).collect()
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let stats = Stats::from_samples(&micros(&[5, 1, 4, 2, 3, 3, 2, 4, 3, 50])).unwrap();
+
+        assert_eq!(stats.samples, 10);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_nanos(7700));
+        assert_eq!(stats.p95, Duration::from_micros(50));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn formats_summary() {
+        let stats = Stats::from_samples(&micros(&[1500, 1200, 1800, 20])).unwrap();
+        let summary = stats.to_string();
+
+        assert_eq!(
+            summary,
+            "└ 4 samples: min 20.0µs, median 1.2ms, mean 1.1ms, p95 1.8ms, stddev 675.1µs, outliers 0"
+        );
+    }
+
+    #[test]
+    fn collects_minimum_samples() {
+        let stats = measure(|x: u64| x * 2, 21, Duration::ZERO);
+        assert!(stats.samples >= 10);
+        assert!(stats.runs >= stats.samples);
+    }
+
+    #[test]
+    fn caps_samples_of_fast_functions() {
+        let stats = measure(|x: u64| x * 2, 21, Duration::from_millis(50));
+        assert!(stats.samples <= 10_000);
+        assert!(stats.runs >= stats.samples);
+    }
+}
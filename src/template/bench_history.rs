@@ -0,0 +1,283 @@
+/// Module that keeps the results of every `cargo time` run in `data/<year>/bench_history.jsonl`,
+/// one JSON object per line, and compares runs with each other.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::{bench::Stats, readme_benchmarks::Timings};
+use crate::Year;
+
+/// Deltas above this percentage are flagged as regressions if no `--threshold` is passed.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse benchmark history: {e}"),
+            Error::IO(e) => write!(f, "could not access benchmark history: {e}"),
+        }
+    }
+}
+
+/// The benchmarks of one day within a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayRun {
+    pub day: u8,
+    pub parse: Option<Stats>,
+    pub part_1: Option<Stats>,
+    pub part_2: Option<Stats>,
+}
+
+impl From<&Timings> for DayRun {
+    fn from(timings: &Timings) -> Self {
+        Self {
+            day: timings.puzzle.day.into_inner(),
+            parse: timings.parse_stats.clone(),
+            part_1: timings.part_1_stats.clone(),
+            part_2: timings.part_2_stats.clone(),
+        }
+    }
+}
+
+/// One `cargo time` run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchRun {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// The git revision the run was made on, if available.
+    pub revision: Option<String>,
+    /// The name given via `--save-baseline`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<String>,
+    pub days: Vec<DayRun>,
+}
+
+impl BenchRun {
+    #[must_use]
+    pub fn new(timings: &[Timings], baseline: Option<String>) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |x| x.as_secs()),
+            revision: get_revision(),
+            baseline,
+            days: timings.iter().map(DayRun::from).collect(),
+        }
+    }
+
+    fn get_day(&self, day: u8) -> Option<&DayRun> {
+        self.days.iter().find(|x| x.day == day)
+    }
+
+    /// Describes the run, e.g. `baseline "before" (a1b2c3d, 2h ago)`.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_secs());
+        let age = format_age(now.saturating_sub(self.timestamp));
+        let revision = self.revision.as_deref().unwrap_or("unknown revision");
+
+        match &self.baseline {
+            Some(name) => format!("baseline \"{name}\" ({revision}, {age})"),
+            None => format!("last run ({revision}, {age})"),
+        }
+    }
+}
+
+/// The change of the median execution time of one step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delta {
+    pub day: u8,
+    /// `None` for the parse step.
+    pub part: Option<u8>,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Delta {
+    /// The change in percent, positive if the step got slower.
+    #[must_use]
+    pub fn percent(&self) -> f64 {
+        let before = self.before.as_nanos() as f64;
+        let after = self.after.as_nanos() as f64;
+        if before == 0.0 {
+            return 0.0;
+        }
+        (after - before) / before * 100.0
+    }
+
+    #[must_use]
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+#[must_use]
+pub fn get_path(year: Year) -> String {
+    format!("data/{year}/bench_history.jsonl")
+}
+
+/// Loads all runs of a year, oldest first. A missing file is treated as an empty history.
+pub fn load(year: Year) -> Result<Vec<BenchRun>, Error> {
+    let path = get_path(year);
+    if !Path::new(&path).exists() {
+        return Ok(vec![]);
+    }
+
+    fs::read_to_string(path)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(|e| Error::Parser(e.to_string())))
+        .collect()
+}
+
+/// Appends a run to the history of a year.
+pub fn append(year: Year, run: &BenchRun) -> Result<(), Error> {
+    let path = get_path(year);
+    if let Some(parent) = Path::new(&path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let line = serde_json::to_string(run).map_err(|e| Error::Parser(e.to_string()))?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+/// Finds the run to compare with: the latest run saved under `baseline`, or the latest run if no name is given.
+#[must_use]
+pub fn find_baseline<'a>(runs: &'a [BenchRun], baseline: Option<&str>) -> Option<&'a BenchRun> {
+    match baseline {
+        Some(name) => runs
+            .iter()
+            .rev()
+            .find(|run| run.baseline.as_deref() == Some(name)),
+        None => runs.last(),
+    }
+}
+
+/// Compares the medians of all steps that were benchmarked in both runs.
+#[must_use]
+pub fn compare(before: &BenchRun, after: &BenchRun) -> Vec<Delta> {
+    let mut deltas = vec![];
+
+    for day in &after.days {
+        let Some(previous) = before.get_day(day.day) else {
+            continue;
+        };
+
+        let steps = [
+            (None, &previous.parse, &day.parse),
+            (Some(1), &previous.part_1, &day.part_1),
+            (Some(2), &previous.part_2, &day.part_2),
+        ];
+
+        for (part, before, after) in steps {
+            if let (Some(before), Some(after)) = (before, after) {
+                deltas.push(Delta {
+                    day: day.day,
+                    part,
+                    before: before.median,
+                    after: after.median,
+                });
+            }
+        }
+    }
+
+    deltas
+}
+
+/// Returns a short description of the checked-out git revision, if git is available.
+fn get_revision() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn format_age(seconds: u64) -> String {
+    match seconds {
+        s if s < 60 => format!("{s}s ago"),
+        s if s < 60 * 60 => format!("{}m ago", s / 60),
+        s if s < 60 * 60 * 24 => format!("{}h ago", s / (60 * 60)),
+        s => format!("{}d ago", s / (60 * 60 * 24)),
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, find_baseline, BenchRun, DayRun};
+    use crate::template::bench::Stats;
+    use std::time::Duration;
+
+    fn stats(micros: u64) -> Option<Stats> {
+        Stats::from_samples(&[Duration::from_micros(micros)])
+    }
+
+    fn run(baseline: Option<&str>, part_1: u64, part_2: Option<u64>) -> BenchRun {
+        BenchRun {
+            timestamp: 0,
+            revision: Some("a1b2c3d".into()),
+            baseline: baseline.map(String::from),
+            days: vec![DayRun {
+                day: 1,
+                parse: None,
+                part_1: stats(part_1),
+                part_2: part_2.and_then(stats),
+            }],
+        }
+    }
+
+    #[test]
+    fn finds_baselines() {
+        let runs = vec![run(Some("before"), 100, None), run(None, 200, None)];
+
+        assert_eq!(find_baseline(&runs, None), Some(&runs[1]));
+        assert_eq!(find_baseline(&runs, Some("before")), Some(&runs[0]));
+        assert_eq!(find_baseline(&runs, Some("after")), None);
+    }
+
+    #[test]
+    fn flags_regressions() {
+        let deltas = compare(&run(None, 100, Some(50)), &run(None, 120, None));
+
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].part, Some(1));
+        assert!((deltas[0].percent() - 20.0).abs() < 1e-9);
+        assert!(deltas[0].is_regression(10.0));
+        assert!(!deltas[0].is_regression(25.0));
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let run = run(Some("before"), 100, Some(50));
+        let json = serde_json::to_string(&run).unwrap();
+        assert_eq!(serde_json::from_str::<BenchRun>(&json).unwrap(), run);
+    }
+}
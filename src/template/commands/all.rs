@@ -1,48 +1,302 @@
-use std::io;
+use std::{fmt::Display, io, process, time::Duration};
 
 use crate::template::{
+    answers::Answers,
+    bench,
+    bench_history::{self, BenchRun},
+    jobs,
     readme_benchmarks::{self, Timings},
+    registry::{self, Registry},
+    report::Report,
+    runner::{self, RunOptions},
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{all_days, Day, DayRange, PuzzleId, Year};
+
+/// Options for comparing a timed run with a previous run from the benchmark history.
+pub struct CompareOptions {
+    /// Compare with the latest run saved under this name instead of the latest run.
+    pub baseline: Option<String>,
+    /// Slowdowns above this percentage are flagged as regressions.
+    pub threshold: f64,
+}
+
+/// Selects the days that are run.
+pub struct DayFilter {
+    pub range: DayRange,
+    /// Days that are skipped even if they are part of `range`.
+    pub skip: Vec<DayRange>,
+    /// Only run days that do not have correct answers on record for both parts.
+    pub only_unsolved: bool,
+    /// Only show days that report errors or answers that differ from the ones on record.
+    pub only_failing: bool,
+}
+
+impl Default for DayFilter {
+    fn default() -> Self {
+        Self {
+            range: DayRange::all(),
+            skip: vec![],
+            only_unsolved: false,
+            only_failing: false,
+        }
+    }
+}
+
+impl DayFilter {
+    /// Whether a day is run. Days are only checked for `only_failing` after running them.
+    fn selects(&self, day: Day, answers: &Answers) -> bool {
+        self.range.contains(day)
+            && !self.skip.iter().any(|skip| skip.contains(day))
+            && !(self.only_unsolved && answers.is_solved(day))
+    }
 
-pub fn handle(is_release: bool, is_timed: bool) {
+    /// Whether some days are left out.
+    fn is_partial(&self) -> bool {
+        self.range != DayRange::all()
+            || !self.skip.is_empty()
+            || self.only_unsolved
+            || self.only_failing
+    }
+
+    fn needs_answers(&self) -> bool {
+        self.only_unsolved
+    }
+}
+
+/// The arguments of `cargo all`.
+pub struct AllOptions {
+    pub year: Year,
+    pub is_release: bool,
+    pub is_timed: bool,
+    /// The time budget of the benchmark of every step in milliseconds, passed on to the solutions.
+    pub budget: Option<u64>,
+    /// The timeout of every step in seconds, passed on to the solutions.
+    pub timeout: Option<u64>,
+    /// The number of days that run at the same time.
+    pub jobs: Option<usize>,
+    pub filter: DayFilter,
+    pub compare: Option<CompareOptions>,
+    /// Saves a timed run in the benchmark history under this name.
+    pub save_baseline: Option<String>,
+}
+
+pub fn handle(options: AllOptions, registry: Option<Registry>) {
+    let AllOptions {
+        year,
+        is_release,
+        is_timed,
+        budget,
+        timeout,
+        jobs,
+        filter,
+        compare,
+        save_baseline,
+    } = options;
     let mut timings: Vec<Timings> = vec![];
 
-    all_days().for_each(|day| {
-        if day > 1 {
+    let answers = if filter.needs_answers() {
+        match Answers::load(year) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("failed to load answers: {e}");
+                process::exit(1);
+            }
+        }
+    } else {
+        Answers::default()
+    };
+
+    let puzzles: Vec<PuzzleId> = all_days()
+        .filter(|&day| filter.selects(day, &answers))
+        .map(|day| PuzzleId::new(year, day))
+        .collect();
+
+    if puzzles.is_empty() {
+        println!("No days match the filter.");
+        return;
+    }
+    let options = RunOptions {
+        budget: is_timed.then(|| budget.map_or(bench::DEFAULT_BUDGET, Duration::from_millis)),
+        timeout: runner::get_timeout(timeout),
+    };
+    let child_options = child_commands::ChildOptions {
+        is_timed,
+        is_release,
+        budget,
+        timeout,
+    };
+
+    // timed runs execute one day after the other, so that benchmarks do not compete for the CPU.
+    let jobs = match (is_timed, jobs, registry) {
+        (true, _, _) => 1,
+        (false, Some(jobs), _) => jobs,
+        (false, None, Some(_)) => jobs::default_jobs(),
+        (false, None, None) => 1,
+    };
+
+    let mut shown = 0;
+    let mut print_header = |puzzle: PuzzleId| {
+        if shown > 0 {
             println!();
         }
+        shown += 1;
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
         println!("------");
+    };
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
-
-        if output.is_empty() {
+    let mut record = |puzzle: PuzzleId, reports: &[Report]| {
+        if reports.is_empty() {
             println!("Not solved.");
         } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+            timings.push(child_commands::get_timings(reports, puzzle));
+        }
+    };
+
+    // with `only_failing`, output has to be captured to decide whether it is shown.
+    if registry.is_none() && jobs <= 1 && !filter.only_failing {
+        // a single child is run with its output forwarded while it runs.
+        for &puzzle in &puzzles {
+            print_header(puzzle);
+            let reports = child_commands::run_solution(puzzle, child_options).unwrap();
+            record(puzzle, &reports);
         }
-    });
+    } else {
+        let run_day = |&puzzle: &PuzzleId| match registry {
+            Some(registry) => {
+                child_commands::Captured::from(registry::run_puzzle(registry, puzzle, options))
+            }
+            None => match child_commands::capture_solution(puzzle, child_options) {
+                Ok(captured) => captured.unwrap_or_default(),
+                Err(e) => child_commands::Captured::from(Err(e.to_string())),
+            },
+        };
+
+        // the output of every day is printed at once, in order, as soon as all previous days are done.
+        jobs::for_each_ordered(&puzzles, jobs, run_day, |&puzzle, captured| {
+            if filter.only_failing && !is_failing(&captured) {
+                // the day was still benchmarked, its timings count towards the total.
+                if !captured.reports.is_empty() {
+                    record(puzzle, &captured.reports);
+                }
+                return;
+            }
+
+            print_header(puzzle);
+            captured.print();
+
+            // the error of a solution that could not be run was printed already.
+            if !captured.failed {
+                record(puzzle, &captured.reports);
+            }
+        });
+    }
+
+    if shown == 0 {
+        println!("No failing days.");
+    }
 
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
         println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
 
+        let run = BenchRun::new(&timings, save_baseline);
+
+        if let Some(options) = compare {
+            print_comparison(year, &run, &options);
+        }
+
         if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
-                Err(_) => {
-                    eprintln!("Failed to update readme with benchmarks.");
+            match bench_history::append(year, &run) {
+                Ok(()) => println!("Saved benchmarks to \"{}\".", bench_history::get_path(year)),
+                Err(e) => eprintln!("Failed to save benchmarks: {e}"),
+            }
+
+            // the table is only complete if every day was run.
+            if filter.is_partial() {
+                println!("Skipped updating README, not all days were run.");
+            } else {
+                match readme_benchmarks::update(year, timings, total_millis) {
+                    Ok(()) => println!("Successfully updated README with benchmarks."),
+                    Err(_) => {
+                        eprintln!("Failed to update readme with benchmarks.");
+                    }
                 }
             }
         }
     }
 }
 
+/// Whether a solution could not be run or one of its steps failed, see [`PartOutcome::is_failure`].
+/// Solutions check their answers against the ledger themselves, so wrong answers count as failures too.
+///
+/// [`PartOutcome::is_failure`]: crate::template::report::PartOutcome::is_failure
+fn is_failing(captured: &child_commands::Captured) -> bool {
+    captured.failed || captured.reports.iter().any(Report::is_failure)
+}
+
+/// Prints the change of every step compared with the baseline, flagging regressions.
+fn print_comparison(year: Year, run: &BenchRun, options: &CompareOptions) {
+    let runs = match bench_history::load(year) {
+        Ok(runs) => runs,
+        Err(e) => {
+            eprintln!("Failed to load benchmarks: {e}");
+            return;
+        }
+    };
+
+    let Some(baseline) = bench_history::find_baseline(&runs, options.baseline.as_deref()) else {
+        match &options.baseline {
+            Some(name) => eprintln!("\nNo benchmarks saved under baseline \"{name}\"."),
+            None => eprintln!("\nNo previous benchmarks to compare with."),
+        }
+        return;
+    };
+
+    println!(
+        "\n{ANSI_BOLD}Compared with {}:{ANSI_RESET}",
+        baseline.describe()
+    );
+
+    let deltas = bench_history::compare(baseline, run);
+    let mut regressions = 0;
+
+    for delta in &deltas {
+        let step = delta
+            .part
+            .map_or("Parse".into(), |part| format!("Part {part}"));
+        let is_regression = delta.is_regression(options.threshold);
+
+        if is_regression {
+            regressions += 1;
+        }
+
+        println!(
+            "Day {:02} {step:<6} {:>9.1?} → {:>9.1?} {:>+7.1}%{}",
+            delta.day,
+            delta.before,
+            delta.after,
+            delta.percent(),
+            if is_regression {
+                format!(" {ANSI_BOLD}▲ regression{ANSI_RESET}")
+            } else {
+                String::new()
+            }
+        );
+    }
+
+    if regressions > 0 {
+        println!(
+            "{regressions} step(s) got more than {}% slower.",
+            options.threshold
+        );
+    } else if !deltas.is_empty() {
+        println!("No regressions above {}%.", options.threshold);
+    }
+}
+
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
@@ -56,16 +310,24 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not read the output of the solution."),
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "could not run the solution: {e}"),
+        }
+    }
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading their reports.
+/// The binaries are run with `--format json`, so that results and timings do not have to be scraped from their output.
+pub(crate) mod child_commands {
+    use super::Error;
+    use crate::template::get_path_for_bin;
+    use crate::template::report::{PartOutcome, Report};
+    use crate::PuzzleId;
     use std::{
         io::{BufRead, BufReader},
         path::Path,
@@ -73,31 +335,69 @@ mod child_commands {
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+    /// The flags passed on to the solution binaries.
+    /// Running a solution and capturing its output take the same options, so that neither drops a flag.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct ChildOptions {
+        pub is_timed: bool,
+        pub is_release: bool,
+        /// The benchmark budget in milliseconds.
+        pub budget: Option<u64>,
+        /// The timeout in seconds.
+        pub timeout: Option<u64>,
+    }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    fn build_args(puzzle: PuzzleId, options: ChildOptions) -> Vec<String> {
+        let ChildOptions {
+            is_timed,
+            is_release,
+            budget,
+            timeout,
+        } = options;
+
+        let mut args: Vec<String> = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            puzzle.to_string(),
+        ];
 
         if is_release {
-            args.push("--release");
+            args.push("--release".into());
+        }
+
+        args.extend(["--".into(), "--format".into(), "json".into()]);
+
+        if let Some(timeout) = timeout {
+            args.push("--timeout".into());
+            args.push(timeout.to_string());
         }
 
         if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            // mirror `--time` and `--budget` flags to child invocations.
+            args.push("--time".into());
+
+            if let Some(budget) = budget {
+                args.push("--budget".into());
+                args.push(budget.to_string());
+            }
+        }
+
+        args
+    }
+
+    /// Run the solution bin for a given day, printing its reports as they arrive.
+    pub fn run_solution(puzzle: PuzzleId, options: ChildOptions) -> Result<Vec<Report>, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
+            return Ok(vec![]);
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward stderr while collecting the reports from stdout.
 
         let mut cmd = Command::new("cargo")
-            .args(&args)
+            .args(build_args(puzzle, options))
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -105,7 +405,7 @@ mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let mut reports = vec![];
 
         let thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
@@ -115,75 +415,148 @@ mod child_commands {
 
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+            match serde_json::from_str::<Report>(&line) {
+                Ok(report) => {
+                    println!("{report}");
+                    reports.push(report);
+                }
+                // solutions may print debug output of their own.
+                Err(_) => println!("{line}"),
+            }
         }
 
         thread.join().unwrap();
         cmd.wait()?;
 
-        Ok(output)
+        Ok(reports)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// The output of a solution that was run without forwarding its output.
+    #[derive(Debug, Default)]
+    pub struct Captured {
+        pub reports: Vec<Report>,
+        /// The output as it would have been printed, with reports formatted like the regular output of a solution.
+        pub stdout: String,
+        pub stderr: String,
+        /// Whether the solution could not be run, e.g. because it did not compile or panicked.
+        pub failed: bool,
+    }
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+    impl Captured {
+        pub fn print(&self) {
+            print!("{}", self.stdout);
+            eprint!("{}", self.stderr);
+        }
+    }
+
+    /// Converts the result of an in-process solution.
+    impl From<Result<Option<Vec<Report>>, String>> for Captured {
+        fn from(result: Result<Option<Vec<Report>>, String>) -> Self {
+            match result {
+                Ok(reports) => {
+                    let reports = reports.unwrap_or_default();
+                    let stdout = reports.iter().map(|report| format!("{report}\n")).collect();
+                    Self {
+                        reports,
+                        stdout,
+                        ..Self::default()
+                    }
                 }
+                Err(e) => Self {
+                    stderr: format!("{e}\n"),
+                    failed: true,
+                    ..Self::default()
+                },
+            }
+        }
+    }
+
+    /// Run the solution bin for a given day without forwarding its output.
+    /// Returns [`None`] for days that have not been scaffolded yet.
+    pub fn capture_solution(
+        puzzle: PuzzleId,
+        options: ChildOptions,
+    ) -> Result<Option<Captured>, Error> {
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
+            return Ok(None);
+        }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+        let output = Command::new("cargo")
+            .args(build_args(puzzle, options))
+            .output()?;
+
+        let mut captured = Captured {
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            ..Captured::default()
+        };
+
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            match serde_json::from_str::<Report>(line) {
+                Ok(report) => {
+                    captured.stdout.push_str(&format!("{report}\n"));
+                    captured.reports.push(report);
                 }
+                // solutions may print debug output of their own.
+                Err(_) => captured.stdout.push_str(&format!("{line}\n")),
+            }
+        }
 
-                timings.total_nanos += nanos;
-            });
+        if !output.status.success() && captured.reports.is_empty() {
+            return Err(Error::Parser(captured.stderr.trim().to_string()));
+        }
 
-        timings
+        Ok(Some(captured))
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Returns the answers of both parts.
+    pub fn get_answers(reports: &[Report]) -> [Option<String>; 2] {
+        [1, 2].map(|part| {
+            reports
+                .iter()
+                .find(|report| report.part == Some(part))
+                .and_then(|report| report.answer().map(String::from))
+        })
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+    /// Collects the timings of benchmarked steps.
+    /// Failed steps show their [`crate::template::report::PartOutcome::status`] instead and do not count towards the total.
+    pub fn get_timings(reports: &[Report], puzzle: PuzzleId) -> super::Timings {
+        let mut timings = super::Timings {
+            puzzle,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            parse_stats: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            total_nanos: 0_f64,
+        };
+
+        for report in reports {
+            let status = report.outcome.as_ref().and_then(PartOutcome::status);
+
+            let (timing, stats) = match (status, &report.stats) {
+                (Some(status), _) => (Some(status.to_string()), None),
+                (None, Some(stats)) => (
+                    Some(format!("{:.1?}", report.duration)),
+                    Some(stats.clone()),
+                ),
+                (None, None) => continue,
+            };
+
+            match report.part {
+                None => (timings.parse, timings.parse_stats) = (timing, stats),
+                Some(1) => (timings.part_1, timings.part_1_stats) = (timing, stats),
+                Some(2) => (timings.part_2, timings.part_2_stats) = (timing, stats),
+                Some(_) => continue,
+            }
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+            if status.is_none() {
+                timings.total_nanos += report.duration.as_nanos() as f64;
+            }
+        }
 
-        Some((str_timing, parsed_timing))
+        timings
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -202,53 +575,143 @@ mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
+        use super::{build_args, get_answers, get_timings, ChildOptions};
+        use crate::template::{
+            bench::Stats,
+            report::{PartOutcome, Report},
+        };
+        use crate::{day, year, PuzzleId};
+        use std::time::Duration;
+
+        const PUZZLE: PuzzleId = PuzzleId::new(year!(2023), day!(1));
+
+        fn report(part: Option<u8>, answer: Option<&str>, nanos: &[u64]) -> Report {
+            let samples: Vec<Duration> = nanos.iter().map(|&x| Duration::from_nanos(x)).collect();
+            let stats = (samples.len() > 1).then(|| Stats::from_samples(&samples).unwrap());
+            let duration = stats.as_ref().map_or(samples[0], |stats| stats.median);
+
+            let mut report = Report::new(PUZZLE, part, duration, stats);
+            report.outcome = match (part, answer) {
+                (_, Some(answer)) => Some(PartOutcome::Answer(answer.into())),
+                (Some(_), None) => Some(PartOutcome::NotImplemented),
+                (None, None) => None,
+            };
+            report
+        }
 
         #[test]
         fn test_well_formed() {
-            let res = parse_exec_time(
+            let res = get_timings(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    report(Some(1), Some("0"), &[74, 74, 75]),
+                    report(Some(2), Some("10"), &[74_130_000, 74_130_000]),
                 ],
-                day!(1),
+                PUZZLE,
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+            assert_eq!(res.part_2_stats.unwrap().samples, 2);
         }
 
         #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
+        fn test_parse_phase() {
+            let res = get_timings(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    report(None, None, &[1_500_000, 1_500_000]),
+                    report(Some(1), Some("0"), &[74, 74]),
+                    report(Some(2), Some("10"), &[1_000_000, 1_000_000]),
                 ],
-                day!(1),
+                PUZZLE,
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos, 2500074_f64);
+            assert_eq!(res.parse.unwrap(), "1.5ms");
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "1.0ms");
         }
 
         #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn test_answers_with_patterns() {
+            let reports = [
+                report(
+                    Some(1),
+                    Some("@ @ ( ) ms (2s @ 5 samples)"),
+                    &[2_000_000_000, 2_000_000_000],
+                ),
+                report(Some(2), Some("#..#\n####"), &[100]),
+            ];
+            let res = get_timings(&reports, PUZZLE);
+            assert_approx_eq!(res.total_nanos, 2000000000_f64);
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2, None);
+            assert_eq!(
+                get_answers(&reports),
+                [
+                    Some("@ @ ( ) ms (2s @ 5 samples)".into()),
+                    Some("#..#\n####".into())
+                ]
             );
+        }
+
+        #[test]
+        fn test_failed_parts() {
+            let mut timed_out = report(Some(2), None, &[60_000_000_000]);
+            timed_out.outcome = Some(PartOutcome::TimedOut);
+
+            let res = get_timings(&[report(Some(1), Some("0"), &[74, 74]), timed_out], PUZZLE);
+            assert_approx_eq!(res.total_nanos, 74_f64);
+            assert_eq!(res.part_2.unwrap(), "timeout");
+            assert!(res.part_2_stats.is_none());
+
+            let mut wrong = report(Some(1), Some("1"), &[74, 74]);
+            wrong.outcome = Some(PartOutcome::WrongAnswer {
+                answer: "1".into(),
+                expected: Some("0".into()),
+            });
+
+            let res = get_timings(&[wrong.clone()], PUZZLE);
+            assert_approx_eq!(res.total_nanos, 0_f64);
+            assert_eq!(res.part_1.unwrap(), "wrong");
+            assert_eq!(get_answers(&[wrong]), [Some("1".into()), None]);
+        }
+
+        #[test]
+        fn test_timed_args() {
+            // `cargo time --only-failing` captures the output, it still has to benchmark.
+            let options = ChildOptions {
+                is_timed: true,
+                is_release: true,
+                budget: Some(500),
+                timeout: None,
+            };
+
+            let args = build_args(PUZZLE, options);
+            assert_eq!(
+                args[3..],
+                [
+                    "2023-01",
+                    "--release",
+                    "--",
+                    "--format",
+                    "json",
+                    "--time",
+                    "--budget",
+                    "500"
+                ]
+            );
+
+            let args = build_args(PUZZLE, ChildOptions::default());
+            assert!(!args.contains(&"--time".into()));
+        }
+
+        #[test]
+        fn test_missing_parts() {
+            let reports = [report(Some(1), None, &[10]), report(Some(2), None, &[10])];
+            let res = get_timings(&reports, PUZZLE);
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(get_answers(&reports), [None, None]);
         }
     }
 }
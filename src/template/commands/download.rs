@@ -1,15 +1,104 @@
-use crate::template::aoc_cli;
-use crate::Day;
-use std::process;
+use crate::template::aoc_client::{self, AocClient, AocClientError};
+use crate::template::{aoc_cli, examples, get_data_path, get_input_path, get_puzzle_path, Backend};
+use crate::PuzzleId;
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+pub fn handle(puzzle: PuzzleId) {
+    if Backend::from_env() == Backend::AocCli {
+        if aoc_cli::check().is_err() {
+            eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+            process::exit(1);
+        }
+
+        if let Err(e) = aoc_cli::download(puzzle) {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        };
+
+        // aoc-cli only writes the puzzle as markdown, the examples are found in the html of the page.
+        println!("Examples and expected results are only extracted with the native backend.");
+        return;
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = download(puzzle) {
+        eprintln!("failed to download puzzle: {e}");
         process::exit(1);
-    };
+    }
+}
+
+fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    let input = client.get_input(puzzle)?;
+    aoc_client::write_file(&input_path, &input)?;
+
+    let html = client.get_puzzle(puzzle)?;
+    let description = aoc_client::get_description(&html);
+    aoc_client::write_file(&puzzle_path, &description)?;
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+
+    write_examples(puzzle, &html)
+}
+
+/// Writes the examples found in the puzzle description to `data/<year>/examples` and
+/// fills in the expected results of the scaffolded tests.
+/// Files and tests that were already edited are left untouched.
+fn write_examples(puzzle: PuzzleId, html: &str) -> Result<(), AocClientError> {
+    let examples = examples::extract(html);
+    let example_dir = get_data_path("examples", puzzle);
+    let module_path = format!("src/bin/{puzzle}.rs");
+    let original = fs::read_to_string(&module_path).ok();
+    let mut module = original.clone();
+
+    let first_input = examples.first().and_then(|e| e.input.as_deref());
+
+    for (index, example) in examples.iter().enumerate() {
+        let part = index as u8 + 1;
+        // part two only gets its own file if its example differs from the one of part one.
+        let has_own_file =
+            part > 1 && example.input.is_some() && example.input.as_deref() != first_input;
+
+        if let Some(input) = example
+            .input
+            .as_deref()
+            .filter(|_| part == 1 || has_own_file)
+        {
+            let example_path = if has_own_file {
+                format!("{example_dir}/{}-{part}.txt", puzzle.day)
+            } else {
+                format!("{example_dir}/{}.txt", puzzle.day)
+            };
+
+            if fs::read_to_string(&example_path).map_or(true, |s| s.is_empty()) {
+                aoc_client::write_file(&example_path, input)?;
+                println!("🎄 Successfully wrote example to \"{}\".", &example_path);
+            }
+        }
+
+        let answer = example.answer.as_deref().filter(|a| !a.is_empty());
+
+        if let (Some(source), Some(answer)) = (module.as_mut(), answer) {
+            if let Some(updated) = examples::prefill_test(source, part, answer, has_own_file) {
+                *source = updated;
+                println!("🎄 Pre-filled expected result `{answer}` for part {part}.");
+            }
+        }
+    }
+
+    // only touch the module if a result was pre-filled, and not if it was edited in the meantime.
+    if let (Some(original), Some(module)) = (original, module) {
+        if module != original {
+            if fs::read_to_string(&module_path).ok().as_ref() == Some(&original) {
+                aoc_client::write_file(&module_path, &module)?;
+            } else {
+                eprintln!("\"{module_path}\" was changed during the download, the expected results were not pre-filled.");
+            }
+        }
+    }
+
+    Ok(())
 }
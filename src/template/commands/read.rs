@@ -1,16 +1,37 @@
 use std::process;
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::aoc_client::{self, AocClient, AocClientError};
+use crate::template::{aoc_cli, get_puzzle_path, markdown, Backend};
+use crate::PuzzleId;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+pub fn handle(puzzle: PuzzleId) {
+    if Backend::from_env() == Backend::AocCli {
+        if aoc_cli::check().is_err() {
+            eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+            process::exit(1);
+        }
+
+        if let Err(e) = aoc_cli::read(puzzle) {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        };
+
+        return;
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = read(puzzle) {
+        eprintln!("failed to read puzzle: {e}");
         process::exit(1);
-    };
+    }
+}
+
+fn read(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let html = client.get_puzzle(puzzle)?;
+
+    let description = aoc_client::get_description(&html);
+
+    aoc_client::write_file(&get_puzzle_path(puzzle), &description)?;
+    println!("{}", markdown::render(&description));
+    Ok(())
 }
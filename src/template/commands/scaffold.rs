@@ -1,12 +1,13 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
 
-use crate::Day;
+use crate::template::{get_data_path, get_input_path};
+use crate::PuzzleId;
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
 
 pub fn part_one(input: &str) -> Option<u32> {
     None
@@ -22,13 +23,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 }
@@ -39,13 +40,18 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(puzzle: PuzzleId) {
+    let day = puzzle.day;
+    let input_path = get_input_path(puzzle);
+    let example_path = format!("{}/{day}.txt", get_data_path("examples", puzzle));
+    let module_path = format!("src/bin/{puzzle}.rs");
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -57,6 +63,7 @@ pub fn handle(day: Day) {
 
     match file.write_all(
         MODULE_TEMPLATE
+            .replace("YEAR_NUMBER", &puzzle.year.into_inner().to_string())
             .replace("DAY_NUMBER", &day.into_inner().to_string())
             .as_bytes(),
     ) {
@@ -69,6 +76,13 @@ pub fn handle(day: Day) {
         }
     }
 
+    for folder in ["inputs", "examples"] {
+        if let Err(e) = fs::create_dir_all(get_data_path(folder, puzzle)) {
+            eprintln!("Failed to create data folder: {e}");
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
@@ -90,5 +104,8 @@ pub fn handle(day: Day) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!(
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        day, puzzle.year
+    );
 }
@@ -1,9 +1,17 @@
 use std::process::{Command, Stdio};
 
-use crate::Day;
-
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+use crate::PuzzleId;
+
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    time: bool,
+    budget: Option<u64>,
+    timeout: Option<u64>,
+    submit_part: Option<u8>,
+    wait: bool,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if release {
         cmd_args.push("--release".to_string());
@@ -14,10 +22,24 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
+
+        if wait {
+            cmd_args.push("--wait".to_string());
+        }
+    }
+
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.to_string());
     }
 
     if time {
         cmd_args.push("--time".to_string());
+
+        if let Some(budget) = budget {
+            cmd_args.push("--budget".to_string());
+            cmd_args.push(budget.to_string());
+        }
     }
 
     let mut cmd = Command::new("cargo")
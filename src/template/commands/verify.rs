@@ -0,0 +1,175 @@
+use std::process;
+
+use crate::template::answers::{self, Answers};
+use crate::template::commands::all::child_commands;
+use crate::template::jobs;
+use crate::template::registry::{self, Registry};
+use crate::template::runner::{self, RunOptions};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::{all_days, Day, PuzzleId, Year};
+
+/// The result of comparing one part of a solution with the recorded answer.
+#[derive(Debug, PartialEq, Eq)]
+enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// No correct answer has been recorded for this part yet.
+    Unknown,
+}
+
+impl Verdict {
+    fn new(expected: Option<&String>, actual: Option<String>) -> Self {
+        match expected {
+            None => Verdict::Unknown,
+            Some(expected) if actual.as_ref() == Some(expected) => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+                actual,
+            },
+        }
+    }
+
+    fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Fail { .. })
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "✔",
+            Verdict::Fail { .. } => "✖",
+            Verdict::Unknown => "-",
+        }
+    }
+}
+
+pub fn handle(year: Year, day: Option<Day>, is_release: bool, registry: Option<Registry>) {
+    let answers = match Answers::load(year) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("failed to load answers: {e}");
+            process::exit(1);
+        }
+    };
+
+    let days: Vec<Day> = match day {
+        Some(day) => vec![day],
+        None => all_days().collect(),
+    };
+
+    let puzzles: Vec<PuzzleId> = days
+        .into_iter()
+        .map(|day| PuzzleId::new(year, day))
+        .collect();
+
+    // in-process solutions all run concurrently before the matrix is printed.
+    let mut results = registry.map(|registry| {
+        registry::run_all(
+            registry,
+            &puzzles,
+            RunOptions {
+                budget: None,
+                timeout: Some(runner::DEFAULT_TIMEOUT),
+            },
+            jobs::default_jobs(),
+        )
+        .into_iter()
+    });
+
+    println!("{ANSI_BOLD}Day   Part 1   Part 2{ANSI_RESET}");
+
+    let mut failures = vec![];
+
+    for puzzle in puzzles {
+        let day = puzzle.day;
+
+        let result = match results.as_mut() {
+            Some(results) => results.next().unwrap(),
+            None => child_commands::capture_solution(
+                puzzle,
+                child_commands::ChildOptions {
+                    is_release,
+                    ..Default::default()
+                },
+            )
+            .map(|captured| captured.map(|captured| captured.reports))
+            .map_err(|e| e.to_string()),
+        };
+
+        let output = match result {
+            Ok(Some(output)) => output,
+            // days without a solution are only reported when answers are on record.
+            Ok(None) if answers.get(day, 1).is_none() && answers.get(day, 2).is_none() => continue,
+            Ok(None) => vec![],
+            Err(e) => {
+                eprintln!("failed to run {puzzle}: {e}");
+                vec![]
+            }
+        };
+
+        let [part_1, part_2] = child_commands::get_answers(&output);
+
+        let verdicts = [(1, part_1), (2, part_2)].map(|(part, actual)| {
+            let expected = answers.get(day, part).and_then(|x| x.correct.as_ref());
+            (part, Verdict::new(expected, actual))
+        });
+
+        println!(
+            " {day}   {}        {}",
+            verdicts[0].1.symbol(),
+            verdicts[1].1.symbol()
+        );
+
+        failures.extend(
+            verdicts
+                .into_iter()
+                .filter(|(_, verdict)| verdict.is_failure())
+                .map(|(part, verdict)| (day, part, verdict)),
+        );
+    }
+
+    if failures.is_empty() {
+        println!("\nAll recorded answers match.");
+        return;
+    }
+
+    println!();
+    for (day, part, verdict) in &failures {
+        if let Verdict::Fail { expected, actual } = verdict {
+            let actual = actual.as_deref().unwrap_or("no answer");
+            println!("Day {day}, part {part}: expected `{expected}`, got `{actual}`.");
+        }
+    }
+
+    eprintln!(
+        "\n{} part(s) do not match the answers in \"{}\".",
+        failures.len(),
+        answers::get_path(year)
+    );
+    process::exit(1);
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Verdict;
+
+    #[test]
+    fn compares_with_recorded_answer() {
+        let expected = "142".to_string();
+
+        assert_eq!(
+            Verdict::new(Some(&expected), Some("142".into())),
+            Verdict::Pass
+        );
+        assert_eq!(
+            Verdict::new(Some(&expected), None),
+            Verdict::Fail {
+                expected: expected.clone(),
+                actual: None
+            }
+        );
+        assert_eq!(Verdict::new(None, Some("1".into())), Verdict::Unknown);
+    }
+}
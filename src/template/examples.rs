@@ -0,0 +1,154 @@
+/// Module that extracts example inputs and their expected answers from puzzle pages.
+/// The heuristics follow the usual structure of a puzzle: the first code block of a part is its example,
+/// the last emphasized code of a part is the result of that example.
+use crate::template::{aoc_client, markdown};
+
+/// The example of one part of a puzzle.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    /// The first `<pre><code>` block of the part, if it has one.
+    pub input: Option<String>,
+    /// The last `<code><em>` of the part, if it has one.
+    pub answer: Option<String>,
+}
+
+/// Extracts the example of every unlocked part of a puzzle page.
+#[must_use]
+pub fn extract(html: &str) -> Vec<Example> {
+    aoc_client::get_articles(html)
+        .iter()
+        .map(|article| Example {
+            input: find_between(article, "<pre><code>", "</code></pre>", false)
+                .map(|input| input.trim_end_matches('\n').to_string()),
+            answer: find_between(article, "<code><em>", "</em></code>", true),
+        })
+        .collect()
+}
+
+fn find_between(html: &str, open: &str, close: &str, last: bool) -> Option<String> {
+    let start = if last {
+        html.rfind(open)?
+    } else {
+        html.find(open)?
+    } + open.len();
+    let len = html[start..].find(close)?;
+    Some(markdown::text_content(&html[start..start + len]))
+}
+
+/// Fills in the expected result of a scaffolded test (`test_part_one` or `test_part_two`) that still asserts `None`.
+/// Answers that are not integers are filled in as text, see [`format_expected`].
+/// If `read_part_file` is set, the test is switched to the example file of that part via `read_file_part`.
+/// Returns [`None`] if the test was not found or was already edited.
+#[must_use]
+pub fn prefill_test(module: &str, part: u8, answer: &str, read_part_file: bool) -> Option<String> {
+    let test_name = match part {
+        1 => "fn test_part_one()",
+        2 => "fn test_part_two()",
+        _ => return None,
+    };
+
+    let start = module.find(test_name)?;
+    let end = start + module[start..].find("\n    }")?;
+
+    let test = &module[start..end];
+    if !test.contains("assert_eq!(result, None);") {
+        return None;
+    }
+
+    let mut test = test.replace(
+        "assert_eq!(result, None);",
+        &format!("assert_eq!(result, Some({}));", format_expected(answer)),
+    );
+
+    if read_part_file {
+        test = test.replace(
+            "read_file(\"examples\", PUZZLE)",
+            &format!("read_file_part(\"examples\", PUZZLE, {part})"),
+        );
+    }
+
+    Some(format!("{}{test}{}", &module[..start], &module[end..]))
+}
+
+/// Formats an answer as the expression it is compared with: integers as is, anything else as a string literal
+/// that converts into the result type of the part, e.g. `"ABC".into()` for a `String`.
+fn format_expected(answer: &str) -> String {
+    if answer.parse::<i64>().is_ok() {
+        answer.into()
+    } else {
+        format!("{answer:?}.into()")
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, prefill_test, Example};
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 9 ---</h2><p>For example:</p>
+<pre><code>0 3 6
+1 <em>3</em> 6
+</code></pre><p>Sum is <code><em>5</em></code>, so the total is <code><em>114</em></code>.</p></article>
+<p>Your puzzle answer was <code>1</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now it is <code><em>2</em></code>.</p></article>
+</main>"#;
+
+    #[test]
+    fn extracts_examples_per_part() {
+        assert_eq!(
+            extract(PAGE),
+            vec![
+                Example {
+                    input: Some("0 3 6\n1 3 6".into()),
+                    answer: Some("114".into()),
+                },
+                Example {
+                    input: None,
+                    answer: Some("2".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn prefills_scaffolded_tests() {
+        let module = [
+            "    #[test]",
+            "    fn test_part_one() {",
+            "        let result = part_one(&advent_of_code::template::read_file(\"examples\", PUZZLE));",
+            "        assert_eq!(result, None);",
+            "    }",
+            "",
+            "    #[test]",
+            "    fn test_part_two() {",
+            "        let result = part_two(&advent_of_code::template::read_file(\"examples\", PUZZLE));",
+            "        assert_eq!(result, None);",
+            "    }",
+        ]
+        .join("\n");
+
+        let module = prefill_test(&module, 2, "2", true).unwrap();
+        let module = prefill_test(&module, 1, "114", false).unwrap();
+
+        assert!(module
+            .contains("read_file(\"examples\", PUZZLE));\n        assert_eq!(result, Some(114));"));
+        assert!(module.contains(
+            "read_file_part(\"examples\", PUZZLE, 2));\n        assert_eq!(result, Some(2));"
+        ));
+        assert_eq!(prefill_test(&module, 1, "114", false), None);
+    }
+
+    #[test]
+    fn prefills_text_answers() {
+        let module = [
+            "    fn test_part_one() {",
+            "        let result = part_one(&advent_of_code::template::read_file(\"examples\", PUZZLE));",
+            "        assert_eq!(result, None);",
+            "    }",
+        ]
+        .join("\n");
+
+        let module = prefill_test(&module, 1, "EZFCHJAB", false).unwrap();
+        assert!(module.contains("assert_eq!(result, Some(\"EZFCHJAB\".into()));"));
+    }
+}
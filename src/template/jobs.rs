@@ -0,0 +1,101 @@
+/// Module that runs independent jobs, such as the solutions of several days, on a limited number of threads.
+use std::{
+    collections::BTreeMap,
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// The number of jobs used for in-process solutions if none is passed via `--jobs`.
+#[must_use]
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Runs `work` for every item on up to `jobs` threads and passes the results to `consume` in the order of `items`.
+/// A result is consumed as soon as it and all results before it are done, so output stays in order without waiting for every job.
+/// With a single job, the items are processed one after another on the current thread.
+pub fn for_each_ordered<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut consume: impl FnMut(&T, R),
+) {
+    if jobs <= 1 {
+        for item in items {
+            consume(item, work(item));
+        }
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next, work) = (&next, &work);
+
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                if sender.send((index, work(item))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        // the receiver stops once all workers are done and have dropped their senders.
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut current = 0;
+
+        for (index, result) in receiver {
+            pending.insert(index, result);
+
+            while let Some(result) = pending.remove(&current) {
+                consume(&items[current], result);
+                current += 1;
+            }
+        }
+    });
+}
+
+/// Runs `work` for every item on up to `jobs` threads and returns the results in the order of `items`.
+pub fn map_ordered<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let mut results = Vec::with_capacity(items.len());
+    for_each_ordered(items, jobs, work, |_, result| results.push(result));
+    results
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::map_ordered;
+    use std::{thread, time::Duration};
+
+    #[test]
+    fn keeps_order() {
+        let items: Vec<u64> = (0..8).collect();
+
+        // later items finish first.
+        let work = |&x: &u64| {
+            thread::sleep(Duration::from_millis(8 - x));
+            x * 2
+        };
+
+        let expected: Vec<u64> = items.iter().map(|x| x * 2).collect();
+        assert_eq!(map_ordered(&items, 4, work), expected);
+        assert_eq!(map_ordered(&items, 1, work), expected);
+        assert!(map_ordered(&[] as &[u64], 4, work).is_empty());
+    }
+}
@@ -0,0 +1,257 @@
+/// Module that converts puzzle descriptions from html to markdown and renders them for the terminal.
+/// Only the handful of elements used on adventofcode.com puzzle pages are supported.
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+enum Token<'a> {
+    Open(String),
+    Close(String),
+    Text(&'a str),
+}
+
+/// Splits an html fragment into opening tags, closing tags and text. Attributes are dropped.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let Some(len) = rest[start..].find('>') else {
+            break;
+        };
+
+        let tag = &rest[start + 1..start + len];
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        if tag.starts_with('/') {
+            tokens.push(Token::Close(name));
+        } else {
+            tokens.push(Token::Open(name));
+        }
+
+        rest = &rest[start + len + 1..];
+    }
+
+    tokens
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Escapes the characters of prose that markdown would read as markup.
+fn escape_markdown(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('*', "\\*")
+}
+
+/// Returns the decoded text of an html fragment, dropping all tags.
+#[must_use]
+pub fn text_content(html: &str) -> String {
+    tokenize(html)
+        .iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(decode_entities(text)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Converts one html fragment (e.g. an `<article>` of a puzzle page) to markdown.
+#[must_use]
+pub fn from_html(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    // inline code is buffered so that `<code><em>42</em></code>` can be emitted as *`42`*.
+    let mut inline_code: Option<(String, bool)> = None;
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open(tag) => match tag.as_str() {
+                "h2" => out.push_str("## "),
+                "pre" => {
+                    in_pre = true;
+                    out.push_str("```\n");
+                }
+                "code" if !in_pre => inline_code = Some((String::new(), false)),
+                "em" => match inline_code.as_mut() {
+                    Some((_, emphasized)) => *emphasized = true,
+                    None if !in_pre => out.push('*'),
+                    None => {}
+                },
+                "li" => out.push_str("- "),
+                _ => {}
+            },
+            Token::Close(tag) => match tag.as_str() {
+                "h2" | "p" => out.push_str("\n\n"),
+                "pre" => {
+                    in_pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                "code" if !in_pre => {
+                    if let Some((code, emphasized)) = inline_code.take() {
+                        if emphasized {
+                            out.push_str(&format!("*`{code}`*"));
+                        } else {
+                            out.push_str(&format!("`{code}`"));
+                        }
+                    }
+                }
+                "em" if !in_pre && inline_code.is_none() => out.push('*'),
+                "li" => out.push('\n'),
+                "ul" => out.push('\n'),
+                _ => {}
+            },
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                if in_pre {
+                    out.push_str(&text);
+                } else if let Some((code, _)) = inline_code.as_mut() {
+                    code.push_str(&text);
+                } else if out.is_empty() || out.ends_with('\n') {
+                    // drop the whitespace between block elements.
+                    out.push_str(escape_markdown(&text.replace('\n', " ")).trim_start());
+                } else {
+                    out.push_str(&escape_markdown(&text.replace('\n', " ")));
+                }
+            }
+        }
+    }
+
+    format!("{}\n", out.trim())
+}
+
+/// Renders markdown produced by [`from_html`] with ANSI styling.
+#[must_use]
+pub fn render(markdown: &str) -> String {
+    let mut lines = vec![];
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            lines.push(format!("    {line}"));
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            lines.push(format!("{ANSI_BOLD}{heading}{ANSI_RESET}"));
+        } else if let Some(item) = line.strip_prefix("- ") {
+            lines.push(format!("  • {}", render_inline(item)));
+        } else {
+            lines.push(render_inline(line));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Styles `*emphasis*` as italic and `` `code` `` as bold. Outside of code, `\` escapes the next character.
+fn render_inline(line: &str) -> String {
+    let mut out = String::new();
+    let mut in_code = false;
+    let mut in_em = false;
+    let mut chars = line.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' if !in_code => out.extend(chars.next()),
+            '`' => {
+                in_code = !in_code;
+                out.push_str(if in_code { ANSI_BOLD } else { ANSI_RESET });
+                if !in_code && in_em {
+                    out.push_str(ANSI_ITALIC);
+                }
+            }
+            '*' if !in_code => {
+                in_em = !in_em;
+                out.push_str(if in_em { ANSI_ITALIC } else { ANSI_RESET });
+            }
+            ch => out.push(ch),
+        }
+    }
+
+    out
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{from_html, render};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    const ARTICLE: &str = r#"<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is wrong with global <em>snow</em> production.</p>
+<p>For example:</p>
+<pre><code>1abc2
+a&lt;b
+</code></pre>
+<ul>
+<li><span title="yes">Calibration</span> values are <code>12</code>.</li>
+<li>Adding these produces <code><em>142</em></code>.</li>
+</ul>
+<p><a href="/2023/day/1/input" target="_blank">Get your puzzle input</a>.</p>
+</article>"#;
+
+    #[test]
+    fn converts_article() {
+        let expected = [
+            "## --- Day 1: Trebuchet?! ---",
+            "",
+            "Something is wrong with global *snow* production.",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1abc2",
+            "a<b",
+            "```",
+            "",
+            "- Calibration values are `12`.",
+            "- Adding these produces *`142`*.",
+            "",
+            "Get your puzzle input.",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(from_html(ARTICLE), expected);
+    }
+
+    #[test]
+    fn renders_inline_styles() {
+        assert_eq!(
+            render("## Title\nan *em* and `code`"),
+            format!(
+                "{ANSI_BOLD}Title{ANSI_RESET}\nan {ANSI_ITALIC}em{ANSI_RESET} and {ANSI_BOLD}code{ANSI_RESET}"
+            )
+        );
+    }
+
+    #[test]
+    fn escapes_literal_asterisks() {
+        let markdown = from_html("<p>2 * 3 is <em>6</em>, not <code>2*3</code>.</p>");
+        assert_eq!(markdown, "2 \\* 3 is *6*, not `2*3`.\n");
+        assert_eq!(
+            render(&markdown),
+            format!("2 * 3 is {ANSI_ITALIC}6{ANSI_RESET}, not {ANSI_BOLD}2*3{ANSI_RESET}.")
+        );
+    }
+}
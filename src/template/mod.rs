@@ -1,10 +1,23 @@
-use crate::Day;
+use crate::PuzzleId;
 use std::{env, fs};
 
+pub mod answers;
 pub mod aoc_cli;
+pub mod aoc_client;
+pub mod bench;
+pub mod bench_history;
 pub mod commands;
+pub mod examples;
+pub mod jobs;
+pub mod markdown;
 pub mod readme_benchmarks;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod solution;
+pub mod submission;
+
+pub use solution::{Solution, SolutionError, SolutionResult};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -12,37 +25,133 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(get_data_path(folder, puzzle))
+        .join(format!("{}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(get_data_path(folder, puzzle))
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Returns the data folder of a kind (e.g. `inputs`) for the year of a puzzle. E.g. like `data/2023/inputs`.
+#[must_use]
+pub fn get_data_path(folder: &str, puzzle: PuzzleId) -> String {
+    format!("data/{}/{folder}", puzzle.year)
+}
+
+/// Returns the path of the puzzle input. E.g. like `data/2023/inputs/01.txt`.
+#[must_use]
+pub fn get_input_path(puzzle: PuzzleId) -> String {
+    format!("{}/{}.txt", get_data_path("inputs", puzzle), puzzle.day)
+}
+
+/// Returns the path of the puzzle description. E.g. like `data/2023/puzzles/01.md`.
+#[must_use]
+pub fn get_puzzle_path(puzzle: PuzzleId) -> String {
+    format!("{}/{}.md", get_data_path("puzzles", puzzle), puzzle.day)
+}
+
+/// Returns the path of the solution of a puzzle. E.g. like `./src/bin/2023-01.rs`.
+#[must_use]
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{puzzle}.rs")
+}
+
+/// The integration used to talk to adventofcode.com.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// The built-in client in [`aoc_client`].
+    Native,
+    /// The external `aoc` binary, see [`aoc_cli`].
+    AocCli,
+}
+
+impl Backend {
+    /// Reads the backend from the `AOC_BACKEND` environment variable, defaulting to [`Backend::Native`].
+    #[must_use]
+    pub fn from_env() -> Self {
+        match env::var("AOC_BACKEND").as_deref() {
+            Ok("aoc-cli") => Self::AocCli,
+            _ => Self::Native,
+        }
+    }
+}
+
+/// Creates the constants `YEAR`, `DAY` and `PUZZLE` and sets up the input and runner for each part.
+///
+/// The solution is either given by free `part_one` and `part_two` functions, e.g. `solution!(2023, 1)`,
+/// or by a type implementing [`Solution`], e.g. `solution!(2023, 4, Day04)`.
+/// Solutions written before years were supported can keep `solution!(1)`, their year is then read from `AOC_YEAR` at compile time.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    (@constants $year:expr, $day:expr) => {
+        /// The current year.
+        const YEAR: advent_of_code::Year = $year;
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::PuzzleId = advent_of_code::PuzzleId::new(YEAR, DAY);
+    };
+    (@parts) => {
+        fn main() {
+            use advent_of_code::template::runner::*;
+            let input = advent_of_code::template::read_file("inputs", PUZZLE);
+            run_part(part_one, &input, PUZZLE, 1);
+            run_part(part_two, &input, PUZZLE, 2);
+        }
+
+        /// Runs the solution in-process, see [`advent_of_code::template::registry`].
+        #[allow(dead_code)]
+        pub fn run_in_process(
+            input: &str,
+            options: &advent_of_code::template::runner::RunOptions,
+        ) -> Vec<advent_of_code::template::report::Report> {
+            use advent_of_code::template::runner::*;
+            vec![
+                report_part(part_one, input, PUZZLE, 1, options),
+                report_part(part_two, input, PUZZLE, 2, options),
+            ]
+        }
+    };
+    ($year:expr, $day:expr) => {
+        advent_of_code::solution!(@constants advent_of_code::year!($year), $day);
+        advent_of_code::solution!(@parts);
+    };
+    ($year:expr, $day:expr, $solution:ty) => {
+        advent_of_code::solution!(@constants advent_of_code::year!($year), $day);
 
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let input = advent_of_code::template::read_file("inputs", PUZZLE);
+            run_solution::<$solution>(&input, PUZZLE);
         }
+
+        /// Runs the solution in-process, see [`advent_of_code::template::registry`].
+        #[allow(dead_code)]
+        pub fn run_in_process(
+            input: &str,
+            options: &advent_of_code::template::runner::RunOptions,
+        ) -> Vec<advent_of_code::template::report::Report> {
+            advent_of_code::template::runner::report_solution::<$solution>(input, PUZZLE, options)
+        }
+    };
+    ($day:expr) => {
+        advent_of_code::solution!(
+            @constants advent_of_code::Year::__parse_const(env!("AOC_YEAR")),
+            $day
+        );
+        advent_of_code::solution!(@parts);
     };
 }
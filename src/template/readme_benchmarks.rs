@@ -2,9 +2,11 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::{bench::Stats, get_path_for_bin};
+use crate::{PuzzleId, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+/// Every year has its own table, enclosed by markers that start like this.
+static MARKER_PREFIX: &str = "<!--- benchmarking table";
 
 #[derive(Debug)]
 pub enum Error {
@@ -20,9 +22,14 @@ impl From<std::io::Error> for Error {
 
 #[derive(Clone)]
 pub struct Timings {
-    pub day: Day,
+    pub puzzle: PuzzleId,
+    /// Only set for solutions that implement [`crate::template::Solution`].
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub parse_stats: Option<Stats>,
+    pub part_1_stats: Option<Stats>,
+    pub part_2_stats: Option<Stats>,
     pub total_nanos: f64,
 }
 
@@ -31,13 +38,13 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// The marker around the table of a year, e.g. `<!--- benchmarking table 2023 --->`.
+fn get_marker(year: Year) -> String {
+    format!("{MARKER_PREFIX} {year} --->")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -58,73 +65,124 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// Formats the timing of one phase, adding the standard deviation if the distribution is known.
+fn format_cell(timing: Option<String>, stats: Option<&Stats>) -> String {
+    match (timing, stats) {
+        (Some(timing), Some(stats)) => format!("`{timing} ± {:.1?}`", stats.stddev),
+        (Some(timing), None) => format!("`{timing}`"),
+        (None, _) => "`-`".into(),
+    }
+}
+
+/// The end of the last benchmark table of any year.
+fn locate_last_table(readme: &str) -> Option<usize> {
+    let (start, _) = readme.rmatch_indices(MARKER_PREFIX).next()?;
+    let end = readme[start..].find("--->")? + "--->".len();
+    Some(start + end)
+}
+
+fn construct_table(prefix: &str, year: Year, timings: Vec<Timings>, total_millis: f64) -> String {
+    let marker = get_marker(year);
+    let header = format!("{prefix} Benchmarks {year}");
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker.clone(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Day | Parse | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---:  |".into(),
     ];
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(timing.puzzle);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            "| [Day {}]({}) | {} | {} | {} |",
+            timing.puzzle.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_cell(timing.parse, timing.parse_stats.as_ref()),
+            format_cell(timing.part_1, timing.part_1_stats.as_ref()),
+            format_cell(timing.part_2, timing.part_2_stats.as_ref())
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+/// Replaces the table of a year. The table of a year that has none yet is added below the last table.
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let marker = get_marker(year);
+    let table = construct_table("##", year, timings, total_millis);
+
+    if s.contains(&marker) {
+        let positions = locate_table(s, &marker)?;
+        s.replace_range(positions.pos_start..positions.pos_end, &table);
+    } else {
+        let pos = locate_last_table(s)
+            .ok_or_else(|| Error::Parser("Could not find a benchmark table.".into()))?;
+        s.insert_str(pos, &format!("\n\n{table}"));
+    }
+
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+pub fn update(year: Year, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{get_marker, update_content, Timings};
+    use crate::template::bench::Stats;
+    use crate::{day, year, PuzzleId};
+    use std::time::Duration;
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
-                day: day!(1),
+                puzzle: PuzzleId::new(year!(2023), day!(1)),
+                parse: None,
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
+                parse_stats: None,
+                part_1_stats: None,
+                part_2_stats: None,
                 total_nanos: 3e+10,
             },
             Timings {
-                day: day!(2),
+                puzzle: PuzzleId::new(year!(2023), day!(2)),
+                parse: None,
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
+                parse_stats: None,
+                part_1_stats: None,
+                part_2_stats: None,
                 total_nanos: 7e+10,
             },
             Timings {
-                day: day!(4),
+                puzzle: PuzzleId::new(year!(2023), day!(4)),
+                parse: Some("5ms".into()),
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
+                parse_stats: None,
+                part_1_stats: Stats::from_samples(&[
+                    Duration::from_millis(39),
+                    Duration::from_millis(40),
+                    Duration::from_millis(41),
+                ]),
+                part_2_stats: None,
                 total_nanos: 9e+10,
             },
         ]
@@ -134,50 +192,74 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = get_marker(year!(2023));
+        let mut s = format!("{} {} {}", marker, marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let marker = get_marker(year!(2023));
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.contains("## Benchmarks 2023"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        let marker = get_marker(year!(2023));
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.matches(&marker).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
+    #[test]
+    fn adds_table_of_other_year() {
+        let marker = get_marker(year!(2023));
+        let mut s = format!("foo\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2022), vec![], 0.0).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
+
+        assert_eq!(s.matches(&marker).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(
+            s.matches(&get_marker(year!(2022)))
+                .collect::<Vec<&str>>()
+                .len(),
+            2
+        );
+        assert!(s.contains("| [Day 4](./src/bin/2023-04.rs)"));
+        assert!(s.find("## Benchmarks 2023") < s.find("## Benchmarks 2022"));
+        assert!(s.ends_with("<!--- benchmarking table 2022 --->\nbaz"));
+    }
+
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = get_marker(year!(2023));
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker, marker);
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2023 --->",
+            "## Benchmarks 2023",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023-01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `-` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `5ms` | `40ms ± 816.5µs` | `50ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2023 --->",
             "baz",
         ]
         .join("\n");
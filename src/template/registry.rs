@@ -0,0 +1,87 @@
+/// Module that runs solutions linked into the runner binary with the `registry` feature.
+/// With the feature, `build.rs` includes every `src/bin/<year>-<day>.rs` as a module of the runner and lists them as [`RegisteredSolution`]s,
+/// so that `all` and `verify` can call solutions directly instead of spawning `cargo run` for every day.
+use std::{
+    fs,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+};
+
+use crate::template::{get_input_path, jobs, report::Report, runner::RunOptions};
+use crate::PuzzleId;
+
+/// A solution that was linked into the runner binary.
+pub struct RegisteredSolution {
+    pub puzzle: PuzzleId,
+    /// Runs the solution on an input without printing anything. Generated by the `solution!` macro.
+    pub run: fn(&str, &RunOptions) -> Vec<Report>,
+}
+
+/// All solutions linked into the runner binary, ordered by puzzle.
+pub type Registry = &'static [RegisteredSolution];
+
+#[must_use]
+pub fn find(registry: Registry, puzzle: PuzzleId) -> Option<&'static RegisteredSolution> {
+    registry.iter().find(|solution| solution.puzzle == puzzle)
+}
+
+/// Runs a solution on its puzzle input.
+/// The solution runs on its own thread, so that a panic is returned as an error instead of taking down the runner.
+/// If it does not finish within the timeout of `options`, an error is returned and the thread is left running in the background,
+/// as there is no way to stop it.
+pub fn run(
+    solution: &'static RegisteredSolution,
+    options: RunOptions,
+) -> Result<Vec<Report>, String> {
+    let input = fs::read_to_string(get_input_path(solution.puzzle))
+        .map_err(|e| format!("could not open input file: {e}"))?;
+
+    let (sender, receiver) = mpsc::channel();
+
+    // the timeout applies to the whole solution here, the runner would stop the process instead.
+    let inner = RunOptions {
+        timeout: None,
+        ..options
+    };
+    thread::spawn(move || sender.send((solution.run)(&input, &inner)));
+
+    let result = match options.timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(RecvTimeoutError::from),
+    };
+
+    result.map_err(|e| match e {
+        RecvTimeoutError::Timeout => format!(
+            "{} timed out after {:.0?}.",
+            solution.puzzle,
+            options.timeout.unwrap_or_default()
+        ),
+        RecvTimeoutError::Disconnected => format!("{} panicked.", solution.puzzle),
+    })
+}
+
+/// Runs the solutions of several puzzles on up to `jobs` threads.
+/// Returns the results in the order of `puzzles`, `Ok(None)` for puzzles without a registered solution.
+#[must_use]
+pub fn run_all(
+    registry: Registry,
+    puzzles: &[PuzzleId],
+    options: RunOptions,
+    jobs: usize,
+) -> Vec<Result<Option<Vec<Report>>, String>> {
+    jobs::map_ordered(puzzles, jobs, |&puzzle| {
+        run_puzzle(registry, puzzle, options)
+    })
+}
+
+/// Runs the solution of a puzzle, `Ok(None)` if it is not registered.
+pub fn run_puzzle(
+    registry: Registry,
+    puzzle: PuzzleId,
+    options: RunOptions,
+) -> Result<Option<Vec<Report>>, String> {
+    match find(registry, puzzle) {
+        Some(solution) => run(solution, options).map(Some),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,329 @@
+/// Module that defines the machine-readable output of solutions.
+/// With `--format json`, a solution prints one [`Report`] per step as a line of JSON instead of its usual output.
+use std::{env, fmt::Display, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::answers::{Answers, Blocked};
+use crate::template::bench::{self, Stats};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::{Day, PuzzleId};
+
+/// How a solution prints its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    /// Reads the format from the `--format` argument, defaulting to [`Format::Text`].
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        match args
+            .iter()
+            .position(|x| x == "--format")
+            .and_then(|i| args.get(i + 1))
+        {
+            Some(format) if format == "json" => Self::Json,
+            _ => Self::Text,
+        }
+    }
+}
+
+/// What running a part of a solution resulted in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartOutcome {
+    Answer(String),
+    /// The part returned `None`.
+    NotImplemented,
+    /// The part returned an error, rendered as a diagnostic.
+    Error(String),
+    /// The part panicked with this message.
+    Panicked(String),
+    /// The part ran longer than the timeout and the solution was stopped.
+    TimedOut,
+    /// The answer contradicts what is on record in `data/<year>/answers.toml`.
+    WrongAnswer {
+        answer: String,
+        /// The correct answer, if it is known.
+        expected: Option<String>,
+    },
+}
+
+impl PartOutcome {
+    /// Turns an answer into a [`PartOutcome::WrongAnswer`] if the answer ledger knows it to be wrong.
+    #[must_use]
+    pub fn check(self, answers: &Answers, day: Day, part: u8) -> Self {
+        let Self::Answer(answer) = self else {
+            return self;
+        };
+
+        match answers.check(day, part, &answer) {
+            Err(Blocked::AlreadySolved { correct }) if correct != answer => Self::WrongAnswer {
+                answer,
+                expected: Some(correct),
+            },
+            Err(Blocked::KnownIncorrect | Blocked::TooHigh { .. } | Blocked::TooLow { .. }) => {
+                Self::WrongAnswer {
+                    answer,
+                    expected: None,
+                }
+            }
+            _ => Self::Answer(answer),
+        }
+    }
+
+    /// The answer of the part, even if it is wrong.
+    #[must_use]
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            Self::Answer(answer) | Self::WrongAnswer { answer, .. } => Some(answer),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        self.status().is_some()
+    }
+
+    /// A short description of a failure, as shown in the benchmark table.
+    #[must_use]
+    pub fn status(&self) -> Option<&'static str> {
+        match self {
+            Self::Answer(_) | Self::NotImplemented => None,
+            Self::Error(_) => Some("error"),
+            Self::Panicked(_) => Some("panicked"),
+            Self::TimedOut => Some("timeout"),
+            Self::WrongAnswer { .. } => Some("wrong"),
+        }
+    }
+}
+
+/// The outcome of one step of a solution: parsing the input or one of the parts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    /// `None` for the parse step of solutions implementing [`crate::template::Solution`].
+    pub part: Option<u8>,
+    /// Always set for parts. The parse step only has an outcome if it failed.
+    pub outcome: Option<PartOutcome>,
+    /// The execution time, the median if the step was benchmarked.
+    #[serde(with = "bench::nanos")]
+    pub duration: Duration,
+    /// The number of times the step ran, `1` if it was not benchmarked.
+    pub runs: usize,
+    /// The distribution of execution times if the step was benchmarked.
+    pub stats: Option<Stats>,
+}
+
+impl Report {
+    #[must_use]
+    pub fn new(
+        puzzle: PuzzleId,
+        part: Option<u8>,
+        duration: Duration,
+        stats: Option<Stats>,
+    ) -> Self {
+        Self {
+            year: puzzle.year.into_inner(),
+            day: puzzle.day.into_inner(),
+            part,
+            outcome: None,
+            duration,
+            runs: stats.as_ref().map_or(1, |stats| stats.runs),
+            stats,
+        }
+    }
+
+    /// The answer of a part, even if it is wrong.
+    #[must_use]
+    pub fn answer(&self) -> Option<&str> {
+        self.outcome.as_ref().and_then(PartOutcome::answer)
+    }
+
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        self.outcome.as_ref().is_some_and(PartOutcome::is_failure)
+    }
+
+    /// Parses the reports from the output of a solution, skipping lines that are not reports.
+    #[must_use]
+    pub fn parse_all(output: &[String]) -> Vec<Self> {
+        output
+            .iter()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()
+    }
+
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("reports should be serializable")
+    }
+
+    /// The label of the step as shown in the terminal.
+    #[must_use]
+    pub fn label(&self) -> String {
+        match self.part {
+            Some(part) => format!("Part {part}"),
+            None => "Parse".into(),
+        }
+    }
+
+    #[must_use]
+    pub fn format_duration(&self) -> String {
+        if self.runs == 1 {
+            format!("({:.1?})", self.duration)
+        } else {
+            format!("({:.1?} @ {} runs)", self.duration, self.runs)
+        }
+    }
+}
+
+/// Explains how to give a step that timed out more time.
+#[must_use]
+pub fn timeout_hint(timeout: Duration) -> String {
+    format!("stopped after {timeout:.0?}, pass `--timeout <s>` to allow more time or `--timeout 0` to wait forever.")
+}
+
+/// Formats a report like the regular output of a solution.
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = self.label();
+        let duration = self.format_duration();
+
+        match &self.outcome {
+            None => write!(f, "{label}: {duration}")?,
+            Some(PartOutcome::Answer(answer)) if answer.contains('\n') => {
+                write!(f, "{label}: ▼ {duration}")?;
+            }
+            Some(PartOutcome::Answer(answer)) => {
+                write!(f, "{label}: {ANSI_BOLD}{answer}{ANSI_RESET} {duration}")?;
+            }
+            Some(PartOutcome::WrongAnswer { answer, .. }) if answer.contains('\n') => {
+                write!(f, "{label}: ▼ {duration} ✖ wrong")?;
+            }
+            Some(PartOutcome::WrongAnswer { answer, .. }) => {
+                write!(
+                    f,
+                    "{label}: {ANSI_BOLD}{answer}{ANSI_RESET} {duration} ✖ wrong"
+                )?;
+            }
+            Some(PartOutcome::Panicked(_)) => write!(f, "{label}: ✖ panicked")?,
+            Some(PartOutcome::TimedOut) => write!(f, "{label}: ✖ timed out")?,
+            Some(PartOutcome::NotImplemented | PartOutcome::Error(_)) => {
+                write!(f, "{label}: ✖")?;
+            }
+        }
+
+        if let Some(stats) = &self.stats {
+            write!(f, "\n  {stats}")?;
+        }
+
+        match &self.outcome {
+            Some(PartOutcome::Answer(answer)) if answer.contains('\n') => write!(f, "\n{answer}"),
+            Some(PartOutcome::WrongAnswer { answer, expected }) => {
+                if answer.contains('\n') {
+                    write!(f, "\n{answer}")?;
+                }
+                match expected {
+                    Some(expected) => write!(f, "\nexpected `{expected}`."),
+                    None => write!(f, "\nthis answer is known to be wrong."),
+                }
+            }
+            Some(PartOutcome::Error(message) | PartOutcome::Panicked(message)) => {
+                write!(f, "\n{message}")
+            }
+            Some(PartOutcome::TimedOut) => write!(f, "\n{}", timeout_hint(self.duration)),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartOutcome, Report};
+    use crate::template::{answers::Answers, bench::Stats, submission::SubmitOutcome};
+    use crate::{day, year, PuzzleId};
+    use std::time::Duration;
+
+    const PUZZLE: PuzzleId = PuzzleId::new(year!(2023), day!(6));
+
+    #[test]
+    fn round_trips_json() {
+        let mut report = Report::new(
+            PUZZLE,
+            Some(1),
+            Duration::from_nanos(250),
+            Stats::from_samples(&[Duration::from_nanos(250), Duration::from_nanos(300)]),
+        );
+        report.outcome = Some(PartOutcome::Answer("(1 @ 2 samples)".into()));
+
+        let json = report.to_json();
+        assert!(json.starts_with(
+            r#"{"year":2023,"day":6,"part":1,"outcome":{"answer":"(1 @ 2 samples)"},"duration":250,"runs":2,"stats":{"samples":2,"runs":2,"min":250"#
+        ));
+
+        let output = vec!["Part 1: 288".into(), json, String::new()];
+        assert_eq!(Report::parse_all(&output), vec![report]);
+    }
+
+    #[test]
+    fn formats_like_text_output() {
+        let mut report = Report::new(PUZZLE, Some(2), Duration::from_micros(12), None);
+        report.outcome = Some(PartOutcome::Answer("#..#\n####".into()));
+        assert_eq!(report.to_string(), "Part 2: ▼ (12.0µs)\n#..#\n####");
+
+        let report = Report::new(PUZZLE, None, Duration::from_micros(3), None);
+        assert_eq!(report.to_string(), "Parse: (3.0µs)");
+
+        let mut report = Report::new(PUZZLE, Some(1), Duration::from_secs(60), None);
+        report.outcome = Some(PartOutcome::TimedOut);
+        assert!(report
+            .to_string()
+            .starts_with("Part 1: ✖ timed out\nstopped after 60s"));
+        assert_eq!(Report::parse_all(&[report.to_json()]), vec![report]);
+
+        let mut report = Report::new(PUZZLE, Some(1), Duration::from_micros(5), None);
+        report.outcome = Some(PartOutcome::WrongAnswer {
+            answer: "12".into(),
+            expected: Some("13".into()),
+        });
+        assert_eq!(
+            report.to_string(),
+            "Part 1: \u{1b}[1m12\u{1b}[0m (5.0µs) ✖ wrong\nexpected `13`."
+        );
+        assert!(report.is_failure());
+        assert_eq!(report.answer(), Some("12"));
+    }
+
+    #[test]
+    fn checks_answers_against_ledger() {
+        let mut answers = Answers::default();
+        answers.record(day!(6), 1, "288", &SubmitOutcome::Correct);
+        answers.record(day!(6), 2, "10", &SubmitOutcome::Incorrect { hint: None });
+
+        let check =
+            |answer: &str, part| PartOutcome::Answer(answer.into()).check(&answers, day!(6), part);
+
+        assert_eq!(check("288", 1), PartOutcome::Answer("288".into()));
+        assert_eq!(
+            check("289", 1),
+            PartOutcome::WrongAnswer {
+                answer: "289".into(),
+                expected: Some("288".into())
+            }
+        );
+        assert_eq!(check("10", 2).status(), Some("wrong"));
+        assert_eq!(check("11", 2), PartOutcome::Answer("11".into()));
+        assert_eq!(
+            PartOutcome::TimedOut.check(&answers, day!(6), 1).status(),
+            Some("timeout")
+        );
+    }
+}
@@ -1,144 +1,450 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+/// Results are printed for humans, or as JSON lines with `--format json` (see [`Report`]).
+use crate::template::answers::{self, Answers};
+use crate::template::aoc_cli::{self, AocCommandError};
+use crate::template::aoc_client::{self, AocClient};
+use crate::template::bench::{self, Stats};
+use crate::template::report::{self, Format, PartOutcome, Report};
+use crate::template::submission::{self, SubmitOutcome};
+use crate::template::{markdown, Backend, Solution, SolutionResult, ANSI_ITALIC, ANSI_RESET};
+use crate::{ocr, PuzzleError, PuzzleId, Year};
+use std::any::Any;
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Mutex, PoisonError};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// The wall-clock time a step may run if no `--timeout` is passed.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How solution steps are executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunOptions {
+    /// The time budget of benchmarks. `None` if every step is executed once.
+    pub budget: Option<Duration>,
+    /// The wall-clock time the first execution of a step may take before the solution is stopped.
+    /// `None` to wait forever.
+    pub timeout: Option<Duration>,
+}
+
+impl RunOptions {
+    /// Reads the options from the `--time`, `--budget <ms>` and `--timeout <s>` arguments.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let value = |name: &str| -> Option<u64> {
+            let index = args.iter().position(|x| x == name)?;
+            args.get(index + 1)?.parse().ok()
+        };
+
+        let budget = args
+            .iter()
+            .any(|x| x == "--time")
+            .then(|| value("--budget").map_or(bench::DEFAULT_BUDGET, Duration::from_millis));
+
+        Self {
+            budget,
+            timeout: get_timeout(value("--timeout")),
+        }
+    }
+}
+
+/// Converts the value of a `--timeout <s>` argument, where `0` disables the timeout.
+#[must_use]
+pub fn get_timeout(seconds: Option<u64>) -> Option<Duration> {
+    match seconds {
+        None => Some(DEFAULT_TIMEOUT),
+        Some(0) => None,
+        Some(seconds) => Some(Duration::from_secs(seconds)),
+    }
+}
+
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+) {
+    run_part_fallible(|input| Ok(func(input)), input, puzzle, part);
+}
+
+/// Runs a [`Solution`]: parses the input once, then runs both parts on the parsed value.
+/// Parsing and each part are timed separately.
+pub fn run_solution<S: Solution>(input: &str, puzzle: PuzzleId) {
+    let options = RunOptions::from_args();
+
+    if Format::from_args() == Format::Json {
+        for report in report_solution::<S>(input, puzzle, &options) {
+            println!("{}", report.to_json());
+        }
+        return;
+    }
+
+    let (parsed, duration, stats) =
+        run_timed(S::parse, input, |_| {}, &options, puzzle, None, true);
+
+    let parsed = match parsed {
+        Ok(Ok(parsed)) => {
+            println!("Parse:{}", format_duration(&duration, stats.as_ref()));
+            parsed
+        }
+        Ok(Err(e)) => {
+            println!("Parse: ✖");
+            eprintln!("{}", PuzzleError::new(puzzle, None, e.as_ref()));
+            process::exit(1);
+        }
+        Err(message) => {
+            print_result(&PartOutcome::Panicked(message), "Parse", "");
+            process::exit(1);
+        }
+    };
+
+    run_part_fallible(S::part_one, &parsed, puzzle, 1);
+    run_part_fallible(S::part_two, &parsed, puzzle, 2);
+}
+
+fn run_part_fallible<I: Clone, T: Display>(
+    func: impl Fn(I) -> SolutionResult<Option<T>>,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+) {
+    let options = RunOptions::from_args();
+
+    if Format::from_args() == Format::Json {
+        let report = report_part_fallible(func, input, puzzle, Some(part), &options);
+        println!("{}", report.to_json());
+        return;
+    }
+
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, stats) = run_timed(
+        func,
+        input,
+        |result| {
+            if let Ok(answer) = result {
+                print_result(&get_answer_outcome(answer.as_ref()), &part_str, "");
+            }
+        },
+        &options,
+        puzzle,
+        Some(part),
+        true,
+    );
+
+    let drawing = match &result {
+        Ok(Ok(Some(answer))) => Some(answer.to_string()).filter(|answer| ocr::is_drawing(answer)),
+        _ => None,
+    };
+
+    let outcome = get_outcome(result, puzzle, Some(part));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(
+        &outcome,
+        &part_str,
+        &format_duration(&duration, stats.as_ref()),
+    );
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    // show the drawing below the letters read from it, so a misread can be spotted.
+    if let (Some(drawing), Some(answer)) = (drawing, outcome.answer()) {
+        if drawing != answer {
+            println!("{drawing}");
+        }
     }
+
+    if let PartOutcome::Answer(answer) = &outcome {
+        submit_result(answer, puzzle, part);
+    }
+}
+
+/// Runs one part without printing anything and returns its [`Report`].
+pub fn report_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    options: &RunOptions,
+) -> Report {
+    report_part_fallible(|input| Ok(func(input)), input, puzzle, Some(part), options)
+}
+
+/// Runs a [`Solution`] without printing anything and returns the [`Report`] of every step.
+/// If parsing fails, only the report of the parse step is returned.
+pub fn report_solution<S: Solution>(
+    input: &str,
+    puzzle: PuzzleId,
+    options: &RunOptions,
+) -> Vec<Report> {
+    let (parsed, duration, stats) =
+        run_timed(S::parse, input, |_| {}, options, puzzle, None, false);
+    let mut report = Report::new(puzzle, None, duration, stats);
+
+    let parsed = match parsed {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => {
+            report.outcome = Some(PartOutcome::Error(
+                PuzzleError::new(puzzle, None, e.as_ref()).to_string(),
+            ));
+            return vec![report];
+        }
+        Err(message) => {
+            report.outcome = Some(PartOutcome::Panicked(message));
+            return vec![report];
+        }
+    };
+
+    vec![
+        report,
+        report_part_fallible(S::part_one, &parsed, puzzle, Some(1), options),
+        report_part_fallible(S::part_two, &parsed, puzzle, Some(2), options),
+    ]
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+fn report_part_fallible<I: Clone, T: Display>(
+    func: impl Fn(I) -> SolutionResult<Option<T>>,
+    input: I,
+    puzzle: PuzzleId,
+    part: Option<u8>,
+    options: &RunOptions,
+) -> Report {
+    let (result, duration, stats) = run_timed(func, input, |_| {}, options, puzzle, part, false);
+    let mut report = Report::new(puzzle, part, duration, stats);
+    report.outcome = Some(get_outcome(result, puzzle, part));
+    report
+}
+
+/// Converts the result of a part into a [`PartOutcome`].
+/// Answers are checked against the ledger of the year, see [`PartOutcome::check`].
+fn get_outcome<T: Display>(
+    result: Result<SolutionResult<Option<T>>, String>,
+    puzzle: PuzzleId,
+    part: Option<u8>,
+) -> PartOutcome {
+    let outcome = match result {
+        Ok(Ok(answer)) => get_answer_outcome(answer.as_ref()),
+        Ok(Err(e)) => PartOutcome::Error(PuzzleError::new(puzzle, part, e.as_ref()).to_string()),
+        Err(message) => PartOutcome::Panicked(message),
+    };
+
+    match part {
+        Some(part) => check_answer(outcome, puzzle, part),
+        None => outcome,
+    }
+}
+
+/// Checks an outcome against the answer ledger, see [`PartOutcome::check`].
+/// The ledger of a year is loaded once per process. If it cannot be read, a warning is printed once and answers are left unchecked.
+fn check_answer(outcome: PartOutcome, puzzle: PuzzleId, part: u8) -> PartOutcome {
+    static LEDGERS: Mutex<BTreeMap<Year, Option<Answers>>> = Mutex::new(BTreeMap::new());
+
+    let mut ledgers = LEDGERS.lock().unwrap_or_else(PoisonError::into_inner);
+    let answers = ledgers
+        .entry(puzzle.year)
+        .or_insert_with(|| match Answers::load(puzzle.year) {
+            Ok(answers) => Some(answers),
+            Err(e) => {
+                eprintln!("failed to load answers, they are not checked: {e}");
+                None
+            }
+        });
+
+    match answers {
+        Some(answers) => outcome.check(answers, puzzle.day, part),
+        None => outcome,
+    }
+}
+
+/// Drawings of block letters are decoded with [`ocr::recognize`], so they can be compared and submitted like any other answer.
+fn get_answer_outcome<T: Display>(answer: Option<&T>) -> PartOutcome {
+    let Some(answer) = answer else {
+        return PartOutcome::NotImplemented;
+    };
+
+    let answer = answer.to_string();
+
+    if ocr::is_drawing(&answer) {
+        if let Some(text) = ocr::recognize(&answer) {
+            return PartOutcome::Answer(text);
+        }
+    }
+
+    PartOutcome::Answer(answer)
+}
+
+/// Run a solution step. The behavior differs depending on whether a time budget is set:
+///  1. without one, the function is executed once.
+///  2. with one, the function is benched for the budget, see [`bench::measure`].
+///
+/// A panic of the first execution is returned as an error with the panic message.
+/// If it runs longer than the timeout, the process is stopped, see [`start_watchdog`].
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    options: &RunOptions,
+    puzzle: PuzzleId,
+    part: Option<u8>,
+    show_progress: bool,
+) -> (Result<T, String>, Duration, Option<Stats>) {
+    let watchdog = options
+        .timeout
+        .map(|timeout| start_watchdog(timeout, puzzle, part));
+
     let timer = Instant::now();
-    let result = func(input.clone());
+    let result = panic::catch_unwind(AssertUnwindSafe(|| func(input.clone())));
     let base_time = timer.elapsed();
 
+    drop(watchdog);
+
+    let result = match result {
+        Ok(result) => result,
+        Err(payload) => return (Err(get_panic_message(payload.as_ref())), base_time, None),
+    };
+
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    let Some(budget) = options.budget else {
+        return (Ok(result), base_time, None);
     };
 
-    (result, run.0, run.1)
+    if show_progress {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+
+    let stats = bench::measure(func, input, budget);
+    (Ok(result), stats.median, Some(stats))
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Stops the process with a [`PartOutcome::TimedOut`] report once `timeout` has passed, unless the returned sender is dropped before.
+/// A step that runs forever cannot be stopped any other way, as threads cannot be killed.
+/// In-process solutions run without a timeout here, [`crate::template::registry::run`] enforces it instead.
+fn start_watchdog(timeout: Duration, puzzle: PuzzleId, part: Option<u8>) -> mpsc::Sender<()> {
+    let (sender, receiver) = mpsc::channel::<()>();
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    thread::spawn(move || {
+        if receiver.recv_timeout(timeout) != Err(RecvTimeoutError::Timeout) {
+            return;
+        }
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+        let mut report = Report::new(puzzle, part, timeout, None);
+        report.outcome = Some(PartOutcome::TimedOut);
 
-    let mut timers: Vec<Duration> = vec![];
+        match Format::from_args() {
+            Format::Json => println!("{}", report.to_json()),
+            Format::Text => {
+                print_result(&PartOutcome::TimedOut, &report.label(), "");
+                eprintln!("{}", report::timeout_hint(timeout));
+            }
+        }
 
-    for _ in 0..bench_iterations {
-        // need a clone here to make the borrow checker happy.
-        let cloned = input.clone();
-        let timer = Instant::now();
-        func(cloned);
-        timers.push(timer.elapsed());
-    }
+        process::exit(1);
+    });
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    sender
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn get_panic_message(payload: &(dyn Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => (*message).to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "the solution panicked.".into(),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn format_duration(duration: &Duration, stats: Option<&Stats>) -> String {
+    match stats {
+        None => format!(" ({duration:.1?})"),
+        // the distribution goes on its own line, below the headline.
+        Some(stats) => format!(" ({duration:.1?} @ {} runs)\n  {stats}", stats.runs),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+/// Prints the result of a part. Without a duration, it is an intermediate result that is overwritten once benchmarks are done.
+/// Details of failures go to stderr, except for panic messages which were already printed by the panic hook.
+fn print_result(outcome: &PartOutcome, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
-                }
-            } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
-            }
-        }
-        None => {
+    let answer = match outcome {
+        PartOutcome::Answer(answer) | PartOutcome::WrongAnswer { answer, .. } => answer,
+        PartOutcome::NotImplemented => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
                 print!("\r");
                 println!("{part}: ✖             ");
             }
+            return;
+        }
+        PartOutcome::Error(message) => {
+            println!("\r{part}: ✖             ");
+            eprintln!("{message}");
+            return;
+        }
+        PartOutcome::Panicked(_) => {
+            println!("\r{part}: ✖ panicked             ");
+            return;
+        }
+        PartOutcome::TimedOut => {
+            println!("\r{part}: ✖ timed out             ");
+            return;
+        }
+    };
+
+    let status = match outcome {
+        PartOutcome::WrongAnswer { .. } => " ✖ wrong",
+        _ => "",
+    };
+
+    if answer.contains('\n') {
+        let str = format!("{part}: ▼ {duration_str}{status}");
+        if is_intermediate_result {
+            print!("{str}");
+        } else {
+            print!("\r");
+            println!("{str}");
+            println!("{answer}");
+        }
+    } else {
+        let str = format!("{part}: {ANSI_BOLD}{answer}{ANSI_RESET}{duration_str}{status}");
+        if is_intermediate_result {
+            print!("{str}");
+        } else {
+            print!("\r");
+            println!("{str}");
+        }
+    }
+
+    if let PartOutcome::WrongAnswer { expected, .. } = outcome {
+        match expected {
+            Some(expected) => eprintln!("expected `{expected}`."),
+            None => eprintln!("this answer is known to be wrong."),
         }
     }
 }
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. the selected backend is available.
+///  3. the answer does not contradict the answers recorded in the ledger.
+///
+/// With `--wait`, a rate-limited submission is repeated once the cooldown has passed.
+fn submit_result<T: Display>(result: T, puzzle: PuzzleId, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -154,14 +460,156 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    let answer = result.to_string();
+
+    if answer.contains('\n') {
+        eprintln!("Not submitting a multi-line answer, its letters could not be read.");
+        return;
+    }
+
+    let mut answers = match Answers::load(puzzle.year) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("failed to load answers: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(reason) = answers.check(puzzle.day, part, &answer) {
+        eprintln!("Not submitting `{answer}`: {reason}");
+        return;
+    }
+
+    let wait = args.contains(&"--wait".into());
+
+    let outcome = loop {
+        let Some(response) = submit_to_backend(puzzle, part, &answer) else {
+            return;
+        };
+
+        let Some(outcome) = SubmitOutcome::parse(&response) else {
+            return;
+        };
+
+        match outcome {
+            SubmitOutcome::Cooldown {
+                remaining: Some(remaining),
+            } if wait => wait_for_cooldown(remaining),
+            SubmitOutcome::Cooldown {
+                remaining: Some(remaining),
+            } => {
+                eprintln!(
+                    "The answer was not checked, the next one can be submitted in {}s. Append `--wait` to resubmit automatically.",
+                    remaining.as_secs()
+                );
+                return;
+            }
+            SubmitOutcome::Cooldown { remaining: None } => {
+                eprintln!("The answer was not checked and the remaining cooldown could not be read, try again later.");
+                return;
+            }
+            SubmitOutcome::WrongLevel => break find_already_solved(puzzle, part),
+            outcome => break outcome,
+        }
+    };
+
+    if let SubmitOutcome::WrongLevel = outcome {
+        eprintln!("Part {part} can not be submitted, make sure the previous part is solved.");
+    }
+
+    if answers.record(puzzle.day, part, &answer, &outcome) {
+        match answers.save(puzzle.year) {
+            Ok(()) => println!(
+                "Recorded {outcome} answer in \"{}\".",
+                answers::get_path(puzzle.year)
+            ),
+            Err(e) => eprintln!("failed to record answer: {e}"),
+        }
+    }
+}
+
+/// Counts down the remaining cooldown on a single line.
+fn wait_for_cooldown(remaining: Duration) {
+    let mut stdout = stdout();
+    // wait one more second, the remaining time is rounded by the site.
+    let seconds = remaining.as_secs() + 1;
+
+    for left in (1..=seconds).rev() {
+        print!("\r{ANSI_ITALIC}Resubmitting in {left}s...{ANSI_RESET}   ");
+        let _ = stdout.flush();
+        thread::sleep(Duration::from_secs(1));
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("\r{}", " ".repeat(30));
+}
+
+/// The site does not distinguish between solved and locked parts when rejecting a submission.
+/// Looks at the puzzle page to find out whether the part was already solved.
+fn find_already_solved(puzzle: PuzzleId, part: u8) -> SubmitOutcome {
+    let Backend::Native = Backend::from_env() else {
+        return SubmitOutcome::WrongLevel;
+    };
+
+    let html = AocClient::from_env().and_then(|client| client.get_puzzle(puzzle));
+
+    match html {
+        Ok(html) => match submission::find_solved_answer(&html, part) {
+            Some(answer) => SubmitOutcome::AlreadySolved {
+                answer: Some(answer),
+            },
+            None => SubmitOutcome::WrongLevel,
+        },
+        Err(_) => SubmitOutcome::WrongLevel,
+    }
+}
+
+/// Submits an answer via the selected backend and returns the text of the response.
+fn submit_to_backend(puzzle: PuzzleId, part: u8, answer: &str) -> Option<String> {
+    match Backend::from_env() {
+        Backend::AocCli => {
+            if aoc_cli::check().is_err() {
+                eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+                process::exit(1);
+            }
+
+            println!("Submitting result via aoc-cli...");
+            match aoc_cli::submit(puzzle, part, answer) {
+                // aoc-cli exits with an error on rate limits, the response is still worth parsing.
+                Ok(output) | Err(AocCommandError::BadExitStatus(output)) => Some(format!(
+                    "{}{}",
+                    String::from_utf8_lossy(&output.stdout),
+                    String::from_utf8_lossy(&output.stderr)
+                )),
+                Err(e) => {
+                    eprintln!("failed to call aoc-cli: {e}");
+                    None
+                }
+            }
+        }
+        Backend::Native => {
+            let client = match AocClient::from_env() {
+                Ok(client) => client,
+                Err(e) => {
+                    eprintln!("failed to submit result: {e}");
+                    process::exit(1);
+                }
+            };
+
+            println!("Submitting result to adventofcode.com...");
+            match client.submit(puzzle, part, answer) {
+                Ok(html) => {
+                    let response = aoc_client::get_description(&html);
+                    println!("{}", markdown::render(&response));
+                    Some(response)
+                }
+                Err(e) => {
+                    eprintln!("failed to submit result: {e}");
+                    None
+                }
+            }
+        }
+    }
 }
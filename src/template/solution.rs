@@ -0,0 +1,24 @@
+/// Module that defines the [`Solution`] trait, an alternative to free `part_one` / `part_two` functions.
+/// Solutions implementing it parse their input once, share the parsed value between both parts and can report errors.
+use std::{error::Error, fmt::Display};
+
+/// The error type of solutions. Any error can be converted into it with `?`, as can a plain `&str`.
+pub type SolutionError = Box<dyn Error + Send + Sync>;
+
+pub type SolutionResult<T> = Result<T, SolutionError>;
+
+pub trait Solution {
+    /// The value both parts are computed from.
+    type Parsed;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    /// Parses the puzzle input. This step is timed separately from the parts.
+    fn parse(input: &str) -> SolutionResult<Self::Parsed>;
+
+    /// Returns `Ok(None)` while the part is not solved yet.
+    fn part_one(parsed: &Self::Parsed) -> SolutionResult<Option<Self::PartOne>>;
+
+    /// Returns `Ok(None)` while the part is not solved yet.
+    fn part_two(parsed: &Self::Parsed) -> SolutionResult<Option<Self::PartTwo>>;
+}
@@ -0,0 +1,173 @@
+/// Module that interprets the responses adventofcode.com gives to submitted answers.
+use std::{fmt::Display, time::Duration};
+
+/// The hint given together with a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// The verdict of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+    },
+    /// An answer was submitted too recently, the site accepts the next one after `remaining`.
+    /// `remaining` is [`None`] if the time left could not be read from the response.
+    Cooldown {
+        remaining: Option<Duration>,
+    },
+    /// The part was already solved. `answer` is the accepted answer, if it is known.
+    AlreadySolved {
+        answer: Option<String>,
+    },
+    /// The part is not the one the site expects, e.g. part two before part one is solved.
+    WrongLevel,
+}
+
+impl SubmitOutcome {
+    /// Parses the text of a response page (as produced by the native client or aoc-cli).
+    /// Returns [`None`] if the response is not a verdict on the answer.
+    pub fn parse(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            return Some(Self::Correct);
+        }
+
+        if response.contains("That's not the right answer") {
+            let hint = if response.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if response.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            return Some(Self::Incorrect { hint });
+        }
+
+        if response.contains("You gave an answer too recently") {
+            let remaining = response
+                .split("You have ")
+                .nth(1)
+                .and_then(|rest| rest.split(" left to wait").next())
+                .and_then(parse_duration);
+            return Some(Self::Cooldown { remaining });
+        }
+
+        if response.contains("You don't seem to be solving the right level") {
+            return Some(Self::WrongLevel);
+        }
+
+        None
+    }
+}
+
+/// Parses the remaining time as printed by the site, e.g. `45s` or `1m 23s`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    s.split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "s" => Some(value),
+                "m" => Some(value * 60),
+                "h" => Some(value * 60 * 60),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Looks up the accepted answer of a part on its puzzle page.
+#[must_use]
+pub fn find_solved_answer(html: &str, part: u8) -> Option<String> {
+    const PREFIX: &str = "Your puzzle answer was <code>";
+
+    let (start, _) = html.match_indices(PREFIX).nth(usize::from(part) - 1)?;
+    let start = start + PREFIX.len();
+    let len = html[start..].find("</code>")?;
+    Some(html[start..start + len].to_string())
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "correct"),
+            SubmitOutcome::Incorrect { hint: None } => write!(f, "incorrect"),
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+            } => write!(f, "incorrect (too high)"),
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooLow),
+            } => write!(f, "incorrect (too low)"),
+            SubmitOutcome::Cooldown {
+                remaining: Some(remaining),
+            } => write!(f, "cooldown ({}s left)", remaining.as_secs()),
+            SubmitOutcome::Cooldown { remaining: None } => write!(f, "cooldown"),
+            SubmitOutcome::AlreadySolved { .. } => write!(f, "already solved"),
+            SubmitOutcome::WrongLevel => write!(f, "wrong level"),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find_solved_answer, Hint, SubmitOutcome};
+    use std::time::Duration;
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            SubmitOutcome::parse("That's the right answer! You are one gold star closer."),
+            Some(SubmitOutcome::Correct)
+        );
+        assert_eq!(
+            SubmitOutcome::parse("That's not the right answer; your answer is too high."),
+            Some(SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooHigh)
+            })
+        );
+        assert_eq!(
+            SubmitOutcome::parse("That's not the right answer. If you're stuck, ..."),
+            Some(SubmitOutcome::Incorrect { hint: None })
+        );
+        assert_eq!(SubmitOutcome::parse("<html></html>"), None);
+    }
+
+    #[test]
+    fn parses_rate_limits() {
+        assert_eq!(
+            SubmitOutcome::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait."),
+            Some(SubmitOutcome::Cooldown {
+                remaining: Some(Duration::from_secs(83))
+            })
+        );
+        assert_eq!(
+            SubmitOutcome::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 45s left to wait."),
+            Some(SubmitOutcome::Cooldown {
+                remaining: Some(Duration::from_secs(45))
+            })
+        );
+        assert_eq!(
+            SubmitOutcome::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have a while left to wait."),
+            Some(SubmitOutcome::Cooldown { remaining: None })
+        );
+        assert_eq!(
+            SubmitOutcome::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some(SubmitOutcome::WrongLevel)
+        );
+    }
+
+    #[test]
+    fn finds_solved_answers() {
+        let html = "<p>Your puzzle answer was <code>142</code>.</p><p>Your puzzle answer was <code>281</code>.</p>";
+        assert_eq!(find_solved_answer(html, 1), Some("142".into()));
+        assert_eq!(find_solved_answer(html, 2), Some("281".into()));
+        assert_eq!(find_solved_answer("<p></p>", 1), None);
+    }
+}
@@ -0,0 +1,153 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The first year advent of code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of advent (i.e. an integer from 2015 onwards).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2023).unwrap();
+/// assert_eq!(year.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(FIRST_YEAR..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Reads the [`Year`] from the `AOC_YEAR` environment variable,
+    /// returns [`None`] if it is not set or invalid.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    // Not part of the public API
+    // Parses a year in const context, used by `solution!` to read `AOC_YEAR` at compile time.
+    #[doc(hidden)]
+    pub const fn __parse_const(year: &str) -> Self {
+        let bytes = year.as_bytes();
+        let mut value: u16 = 0;
+        let mut i = 0;
+
+        assert!(bytes.len() == 4, "`AOC_YEAR` is not a valid year");
+        while i < bytes.len() {
+            assert!(bytes[i].is_ascii_digit(), "`AOC_YEAR` is not a valid year");
+            value = value * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        assert!(
+            value >= FIRST_YEAR,
+            "`AOC_YEAR` is not a year of advent, expecting 2015 onwards"
+        );
+        Self(value)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl PartialOrd<u16> for Year {
+    fn partial_cmp(&self, other: &u16) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of advent from 2015 onwards")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015 && $year <= 9999,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a year of advent from 2015 onwards"
+            ),
+        );
+        $crate::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_valid_years() {
+        assert_eq!("2015".parse::<Year>().unwrap(), Year(2015));
+        assert_eq!("2023".parse::<Year>().unwrap(), Year(2023));
+
+        const YEAR: Year = Year::__parse_const("2021");
+        assert_eq!(YEAR, Year(2021));
+    }
+
+    #[test]
+    fn rejects_invalid_years() {
+        assert!("2014".parse::<Year>().is_err());
+        assert!("23".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */